@@ -0,0 +1,4 @@
+pub const TOKEN_DECIMAL: u8 = 6;          // token decimal
+pub const MAX_FEE_BPS: u16 = 10_000;      // 100% in basis points
+pub const BPS_DENOMINATOR: u64 = 10_000;  // basis points in 100%
+pub const EXPONENTIAL_STEPS: u64 = 100;   // price steps over the supply of an exponential curve
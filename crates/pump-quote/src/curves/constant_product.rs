@@ -0,0 +1,84 @@
+use super::PriceCurve;
+use crate::{
+    consts::TOKEN_DECIMAL,
+    error::{QuoteError, Result},
+    math::{ceil_div, mul_div, to_u64},
+};
+
+//  x * y = k on the virtual reserves
+pub struct ConstantProductCurve {
+    pub virtual_sol_reserves: u64,
+    pub virtual_token_reserves: u64,
+}
+
+impl PriceCurve for ConstantProductCurve {
+    fn buy_amount_out(&self, lamports_in: u64) -> Result<u64> {
+        //  tokens_out = token_reserves * in / (sol_reserves + in), rounded down
+        let sol_after = (self.virtual_sol_reserves as u128)
+            .checked_add(lamports_in as u128)
+            .ok_or(QuoteError::Overflow)?;
+
+        to_u64(mul_div(
+            self.virtual_token_reserves as u128,
+            lamports_in as u128,
+            sol_after,
+            false,
+        )?)
+    }
+
+    fn buy_amount_in(&self, tokens_out: u64) -> Result<u64> {
+        //  lamports_in = sol_reserves * out / (token_reserves - out), rounded up
+        let tokens_after = self
+            .virtual_token_reserves
+            .checked_sub(tokens_out)
+            .filter(|remaining| *remaining > 0)
+            .ok_or(QuoteError::Overflow)?;
+
+        to_u64(mul_div(
+            self.virtual_sol_reserves as u128,
+            tokens_out as u128,
+            tokens_after as u128,
+            true,
+        )?)
+    }
+
+    fn sell_amount_out(&self, tokens_in: u64) -> Result<u64> {
+        //  lamports_out = sol_reserves * in / (token_reserves + in), rounded down
+        let tokens_after = (self.virtual_token_reserves as u128)
+            .checked_add(tokens_in as u128)
+            .ok_or(QuoteError::Overflow)?;
+
+        to_u64(mul_div(
+            self.virtual_sol_reserves as u128,
+            tokens_in as u128,
+            tokens_after,
+            false,
+        )?)
+    }
+
+    fn sell_amount_in(&self, lamports_out: u64) -> Result<u64> {
+        //  tokens_in = token_reserves * out / (sol_reserves - out), rounded up
+        let sol_after = self
+            .virtual_sol_reserves
+            .checked_sub(lamports_out)
+            .filter(|remaining| *remaining > 0)
+            .ok_or(QuoteError::Overflow)?;
+
+        to_u64(ceil_div(
+            (self.virtual_token_reserves as u128)
+                .checked_mul(lamports_out as u128)
+                .ok_or(QuoteError::Overflow)?,
+            sol_after as u128,
+        )?)
+    }
+
+    fn spot_price(&self) -> Result<u64> {
+        //  sol_reserves / token_reserves, per one token
+        to_u64(mul_div(
+            self.virtual_sol_reserves as u128,
+            10u128.pow(TOKEN_DECIMAL as u32),
+            self.virtual_token_reserves as u128,
+            false,
+        )?)
+    }
+}
@@ -0,0 +1,128 @@
+use super::IntegralCurve;
+use crate::{
+    consts::{BPS_DENOMINATOR, EXPONENTIAL_STEPS, TOKEN_DECIMAL},
+    error::{QuoteError, Result},
+    math::{mul_div, to_u64},
+};
+
+//  fixed point precision for the price growth factor
+const PRECISION: u128 = 1_000_000_000_000;
+
+//  price grows by growth_bps every 1 / EXPONENTIAL_STEPS of the supply sold
+//  price(sold) = start_price * (1 + growth_bps / 10_000) ^ (sold / step)
+pub struct ExponentialCurve {
+    pub start_price: u64, //  lamports per one token (without decimal) at launch
+    pub growth_bps: u32,  //  price growth per step
+    pub supply: u64,      //  tokens for sale on the curve
+    pub sold: u64,        //  tokens sold so far
+}
+
+impl ExponentialCurve {
+    fn step(&self) -> Result<u128> {
+        let step = self.supply / EXPONENTIAL_STEPS;
+        if step == 0 {
+            return Err(QuoteError::InvalidParameter);
+        }
+
+        Ok(step as u128)
+    }
+
+    //  (1 + growth) ^ steps in fixed point
+    fn factor(&self, mut steps: u128, round_up: bool) -> Result<u128> {
+        let mut base = PRECISION / BPS_DENOMINATOR as u128
+            * (BPS_DENOMINATOR as u128 + self.growth_bps as u128);
+        let mut factor = PRECISION;
+
+        while steps > 0 {
+            if steps & 1 == 1 {
+                factor = mul_div(factor, base, PRECISION, round_up)?;
+            }
+            steps >>= 1;
+            if steps > 0 {
+                base = mul_div(base, base, PRECISION, round_up)?;
+            }
+        }
+
+        Ok(factor)
+    }
+
+    //  sum of the growth factors of steps first..last, in fixed point
+    fn factor_sum(&self, first: u128, last: u128, round_up: bool) -> Result<u128> {
+        if first >= last {
+            return Ok(0);
+        }
+        if self.growth_bps == 0 {
+            return Ok((last - first) * PRECISION);
+        }
+
+        //  factor(first) * (factor(last - first) - 1) / growth
+        let growth = self.factor(last - first, round_up)? - PRECISION;
+        let sum = mul_div(self.factor(first, round_up)?, growth, PRECISION, round_up)?;
+
+        mul_div(
+            sum,
+            BPS_DENOMINATOR as u128,
+            self.growth_bps as u128,
+            round_up,
+        )
+    }
+}
+
+impl IntegralCurve for ExponentialCurve {
+    fn supply(&self) -> u64 {
+        self.supply
+    }
+
+    fn sold(&self) -> u64 {
+        self.sold
+    }
+
+    fn area(&self, from: u64, to: u64, round_up: bool) -> Result<u64> {
+        if from > to {
+            return Err(QuoteError::Overflow);
+        }
+        if from == to {
+            return Ok(0);
+        }
+
+        let step = self.step()?;
+        let (from, to) = (from as u128, to as u128);
+        let (first, last) = (from / step, to / step);
+
+        //  width of each step covered, weighted by that step's price factor
+        let weighted = if first == last {
+            (to - from)
+                .checked_mul(self.factor(first, round_up)?)
+                .ok_or(QuoteError::Overflow)?
+        } else {
+            let head = ((first + 1) * step - from)
+                .checked_mul(self.factor(first, round_up)?)
+                .ok_or(QuoteError::Overflow)?;
+            let body = step
+                .checked_mul(self.factor_sum(first + 1, last, round_up)?)
+                .ok_or(QuoteError::Overflow)?;
+            let tail = (to - last * step)
+                .checked_mul(self.factor(last, round_up)?)
+                .ok_or(QuoteError::Overflow)?;
+
+            head.checked_add(body)
+                .and_then(|sum| sum.checked_add(tail))
+                .ok_or(QuoteError::Overflow)?
+        };
+
+        let tokens = mul_div(weighted, 1, PRECISION, round_up)?;
+
+        to_u64(mul_div(
+            tokens,
+            self.start_price as u128,
+            10u128.pow(TOKEN_DECIMAL as u32),
+            round_up,
+        )?)
+    }
+
+    fn price(&self, sold: u64) -> Result<u64> {
+        let factor = self.factor(sold as u128 / self.step()?, false)?;
+
+        to_u64(mul_div(self.start_price as u128, factor, PRECISION, false)?)
+    }
+}
@@ -0,0 +1,66 @@
+use super::IntegralCurve;
+use crate::{
+    consts::{BPS_DENOMINATOR, TOKEN_DECIMAL},
+    error::{QuoteError, Result},
+    math::{mul_div, to_u64},
+};
+
+//  price grows linearly with tokens sold
+//  price(sold) = start_price * (1 + growth_bps / 10_000 * sold / supply)
+pub struct LinearCurve {
+    pub start_price: u64, //  lamports per one token (without decimal) at launch
+    pub growth_bps: u32,  //  price growth once the whole supply is sold
+    pub supply: u64,      //  tokens for sale on the curve
+    pub sold: u64,        //  tokens sold so far
+}
+
+impl IntegralCurve for LinearCurve {
+    fn supply(&self) -> u64 {
+        self.supply
+    }
+
+    fn sold(&self) -> u64 {
+        self.sold
+    }
+
+    fn area(&self, from: u64, to: u64, round_up: bool) -> Result<u64> {
+        let width = to.checked_sub(from).ok_or(QuoteError::Overflow)? as u128;
+
+        //  width * (1 + growth * (from + to) / (2 * supply)), in token units
+        let base = (self.supply as u128)
+            .checked_mul(2 * BPS_DENOMINATOR as u128)
+            .ok_or(QuoteError::Overflow)?;
+        let slope = (self.growth_bps as u128)
+            .checked_mul(from as u128 + to as u128)
+            .ok_or(QuoteError::Overflow)?;
+        let tokens = mul_div(
+            width,
+            base.checked_add(slope).ok_or(QuoteError::Overflow)?,
+            base,
+            round_up,
+        )?;
+
+        to_u64(mul_div(
+            tokens,
+            self.start_price as u128,
+            10u128.pow(TOKEN_DECIMAL as u32),
+            round_up,
+        )?)
+    }
+
+    fn price(&self, sold: u64) -> Result<u64> {
+        let base = (self.supply as u128)
+            .checked_mul(BPS_DENOMINATOR as u128)
+            .ok_or(QuoteError::Overflow)?;
+        let slope = (self.growth_bps as u128)
+            .checked_mul(sold as u128)
+            .ok_or(QuoteError::Overflow)?;
+
+        to_u64(mul_div(
+            self.start_price as u128,
+            base.checked_add(slope).ok_or(QuoteError::Overflow)?,
+            base,
+            false,
+        )?)
+    }
+}
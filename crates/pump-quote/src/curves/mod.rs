@@ -0,0 +1,132 @@
+use crate::error::{QuoteError, Result};
+
+pub mod constant_product;
+pub use constant_product::*;
+pub mod linear;
+pub use linear::*;
+pub mod exponential;
+pub use exponential::*;
+
+//  pricing formula of a curve family
+//  all lamport amounts are net of fees, every rounding favors the curve
+pub trait PriceCurve {
+    //  tokens out for lamports paid into the curve, rounded down
+    fn buy_amount_out(&self, lamports_in: u64) -> Result<u64>;
+    //  lamports to pay into the curve for tokens out, rounded up
+    fn buy_amount_in(&self, tokens_out: u64) -> Result<u64>;
+    //  lamports out of the curve for tokens sold, rounded down
+    fn sell_amount_out(&self, tokens_in: u64) -> Result<u64>;
+    //  tokens to sell for lamports out of the curve, rounded up
+    fn sell_amount_in(&self, lamports_out: u64) -> Result<u64>;
+    //  lamports per one token (without decimal) at the current state, rounded down
+    fn spot_price(&self) -> Result<u64>;
+}
+
+//  any curve family, dispatched without allocation
+pub enum Curve {
+    ConstantProduct(ConstantProductCurve),
+    Linear(LinearCurve),
+    Exponential(ExponentialCurve),
+}
+
+impl Curve {
+    fn inner(&self) -> &dyn PriceCurve {
+        match self {
+            Self::ConstantProduct(curve) => curve,
+            Self::Linear(curve) => curve,
+            Self::Exponential(curve) => curve,
+        }
+    }
+}
+
+impl PriceCurve for Curve {
+    fn buy_amount_out(&self, lamports_in: u64) -> Result<u64> {
+        self.inner().buy_amount_out(lamports_in)
+    }
+
+    fn buy_amount_in(&self, tokens_out: u64) -> Result<u64> {
+        self.inner().buy_amount_in(tokens_out)
+    }
+
+    fn sell_amount_out(&self, tokens_in: u64) -> Result<u64> {
+        self.inner().sell_amount_out(tokens_in)
+    }
+
+    fn sell_amount_in(&self, lamports_out: u64) -> Result<u64> {
+        self.inner().sell_amount_in(lamports_out)
+    }
+
+    fn spot_price(&self) -> Result<u64> {
+        self.inner().spot_price()
+    }
+}
+
+//  a curve family given by its price per token as a function of tokens sold
+//  trades cost the area under the price between the old and new sold amount
+pub trait IntegralCurve {
+    //  tokens for sale on the curve
+    fn supply(&self) -> u64;
+    //  tokens sold by the curve so far
+    fn sold(&self) -> u64;
+    //  lamports under the price between two sold amounts
+    fn area(&self, from: u64, to: u64, round_up: bool) -> Result<u64>;
+    //  lamports per one token (without decimal) once sold tokens are out, rounded down
+    fn price(&self, sold: u64) -> Result<u64>;
+}
+
+impl<T: IntegralCurve> PriceCurve for T {
+    fn buy_amount_out(&self, lamports_in: u64) -> Result<u64> {
+        let sold = self.sold();
+
+        //  largest amount whose cost fits in lamports_in, never more than is left for sale
+        //  an amount too large to price can't be afforded either
+        let (mut low, mut high) = (0u64, self.supply().saturating_sub(sold));
+        while low < high {
+            let mid = low + (high - low).div_ceil(2);
+            match self.area(sold, sold + mid, true) {
+                Ok(cost) if cost <= lamports_in => low = mid,
+                _ => high = mid - 1,
+            }
+        }
+
+        Ok(low)
+    }
+
+    fn buy_amount_in(&self, tokens_out: u64) -> Result<u64> {
+        let sold = self.sold();
+        let to = sold.checked_add(tokens_out).ok_or(QuoteError::Overflow)?;
+
+        self.area(sold, to, true)
+    }
+
+    fn sell_amount_out(&self, tokens_in: u64) -> Result<u64> {
+        let sold = self.sold();
+        let from = sold.checked_sub(tokens_in).ok_or(QuoteError::Overflow)?;
+
+        self.area(from, sold, false)
+    }
+
+    fn sell_amount_in(&self, lamports_out: u64) -> Result<u64> {
+        let sold = self.sold();
+        if self.area(0, sold, false)? < lamports_out {
+            return Err(QuoteError::Overflow);
+        }
+
+        //  smallest amount whose proceeds cover lamports_out
+        let (mut low, mut high) = (0u64, sold);
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if self.area(sold - mid, sold, false)? >= lamports_out {
+                high = mid;
+            } else {
+                low = mid + 1;
+            }
+        }
+
+        Ok(low)
+    }
+
+    fn spot_price(&self) -> Result<u64> {
+        self.price(self.sold())
+    }
+}
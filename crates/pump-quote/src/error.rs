@@ -0,0 +1,22 @@
+use core::fmt;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QuoteError {
+    //  an overflow or underflow occurred during the calculation
+    Overflow,
+    //  a fee or curve parameter is not in the expected range
+    InvalidParameter,
+}
+
+impl fmt::Display for QuoteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Overflow => {
+                f.write_str("an overflow or underflow occurred during the calculation")
+            }
+            Self::InvalidParameter => f.write_str("the value is not in the expected range"),
+        }
+    }
+}
+
+pub type Result<T> = core::result::Result<T, QuoteError>;
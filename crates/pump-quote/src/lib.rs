@@ -0,0 +1,16 @@
+//! Curve and fee math of the pump program.
+//!
+//! Pure integer math with no Solana account dependencies, so the program and
+//! off-chain clients quote trades with the exact same code.
+
+#![no_std]
+
+pub mod consts;
+pub mod curves;
+pub mod error;
+pub mod math;
+pub mod quote;
+
+pub use curves::*;
+pub use error::*;
+pub use quote::*;
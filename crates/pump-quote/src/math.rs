@@ -0,0 +1,107 @@
+use crate::{
+    consts::MAX_FEE_BPS,
+    error::{QuoteError, Result},
+};
+
+//  fee on a lamport amount in basis points, rounded up
+pub fn calc_fee(amount: u64, fee_bps: u16) -> Result<u64> {
+    if fee_bps > MAX_FEE_BPS {
+        return Err(QuoteError::InvalidParameter);
+    }
+
+    to_u64(mul_div(
+        amount as u128,
+        fee_bps as u128,
+        MAX_FEE_BPS as u128,
+        true,
+    )?)
+}
+
+//  smallest gross amount whose fee-deducted value is at least net
+pub fn gross_up(net: u64, fee_bps: u16) -> Result<u64> {
+    if fee_bps >= MAX_FEE_BPS {
+        return Err(QuoteError::InvalidParameter);
+    }
+
+    to_u64(mul_div(
+        net as u128,
+        MAX_FEE_BPS as u128,
+        (MAX_FEE_BPS - fee_bps) as u128,
+        true,
+    )?)
+}
+
+//  part of a fee paid to someone else, in basis points of the fee, rounded down
+pub fn fee_share(fee: u64, share_bps: u16) -> Result<u64> {
+    if share_bps > MAX_FEE_BPS {
+        return Err(QuoteError::InvalidParameter);
+    }
+
+    to_u64(mul_div(
+        fee as u128,
+        share_bps as u128,
+        MAX_FEE_BPS as u128,
+        false,
+    )?)
+}
+
+//  buy fee while a launch tax decays linearly to the base fee over `decay_slots`, rounded up
+pub fn decayed_fee_bps(
+    base_bps: u16,
+    launch_tax_bps: u16,
+    decay_slots: u64,
+    elapsed_slots: u64,
+) -> Result<u16> {
+    if base_bps > MAX_FEE_BPS || launch_tax_bps > MAX_FEE_BPS {
+        return Err(QuoteError::InvalidParameter);
+    }
+    if launch_tax_bps <= base_bps || elapsed_slots >= decay_slots {
+        return Ok(base_bps);
+    }
+
+    let extra = mul_div(
+        (launch_tax_bps - base_bps) as u128,
+        (decay_slots - elapsed_slots) as u128,
+        decay_slots as u128,
+        true,
+    )?;
+
+    Ok(base_bps + extra as u16)
+}
+
+//  part of a fee charged at `fee_bps` that is above the base rate, rounded down
+pub fn tax_share(fee: u64, base_bps: u16, fee_bps: u16) -> Result<u64> {
+    if fee_bps <= base_bps {
+        return Ok(0);
+    }
+
+    to_u64(mul_div(
+        fee as u128,
+        (fee_bps - base_bps) as u128,
+        fee_bps as u128,
+        false,
+    )?)
+}
+
+pub fn to_u64(value: u128) -> Result<u64> {
+    u64::try_from(value).map_err(|_| QuoteError::Overflow)
+}
+
+pub fn ceil_div(numerator: u128, denominator: u128) -> Result<u128> {
+    if denominator == 0 {
+        return Err(QuoteError::Overflow);
+    }
+
+    Ok(numerator.div_ceil(denominator))
+}
+
+//  value * numerator / denominator in u128, rounded up or down
+pub fn mul_div(value: u128, numerator: u128, denominator: u128, round_up: bool) -> Result<u128> {
+    let product = value.checked_mul(numerator).ok_or(QuoteError::Overflow)?;
+
+    if round_up {
+        ceil_div(product, denominator)
+    } else {
+        product.checked_div(denominator).ok_or(QuoteError::Overflow)
+    }
+}
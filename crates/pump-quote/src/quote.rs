@@ -0,0 +1,204 @@
+use crate::{
+    consts::TOKEN_DECIMAL,
+    curves::{ConstantProductCurve, Curve, ExponentialCurve, LinearCurve, PriceCurve},
+    error::{QuoteError, Result},
+    math::{calc_fee, gross_up, mul_div, to_u64},
+};
+
+//  pricing family of a curve
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CurveKind {
+    ConstantProduct,
+    Linear,
+    Exponential,
+}
+
+//  the parts of a bonding curve account needed to quote a trade
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CurveState {
+    pub kind: CurveKind,
+
+    pub virtual_sol_reserves: u64,
+    pub virtual_token_reserves: u64,
+    pub real_sol_reserves: u64,
+    pub real_token_reserves: u64,
+
+    //  parameters of linear and exponential curves
+    pub initial_real_token_reserves: u64,
+    pub start_price: u64,
+    pub growth_bps: u32,
+}
+
+impl CurveState {
+    //  pricing formula for the curve's family at this state
+    pub fn curve(&self) -> Result<Curve> {
+        let sold = self
+            .initial_real_token_reserves
+            .checked_sub(self.real_token_reserves)
+            .ok_or(QuoteError::Overflow)?;
+
+        Ok(match self.kind {
+            CurveKind::ConstantProduct => Curve::ConstantProduct(ConstantProductCurve {
+                virtual_sol_reserves: self.virtual_sol_reserves,
+                virtual_token_reserves: self.virtual_token_reserves,
+            }),
+            CurveKind::Linear => Curve::Linear(LinearCurve {
+                start_price: self.start_price,
+                growth_bps: self.growth_bps,
+                supply: self.initial_real_token_reserves,
+                sold,
+            }),
+            CurveKind::Exponential => Curve::Exponential(ExponentialCurve {
+                start_price: self.start_price,
+                growth_bps: self.growth_bps,
+                supply: self.initial_real_token_reserves,
+                sold,
+            }),
+        })
+    }
+
+    //  state after a buy, the curve keeps the lamports net of fee
+    pub fn after_buy(&self, quote: &Quote) -> Result<CurveState> {
+        let net_lamports = quote
+            .amount_in
+            .checked_sub(quote.fee)
+            .ok_or(QuoteError::Overflow)?;
+
+        Ok(CurveState {
+            virtual_sol_reserves: self
+                .virtual_sol_reserves
+                .checked_add(net_lamports)
+                .ok_or(QuoteError::Overflow)?,
+            virtual_token_reserves: self
+                .virtual_token_reserves
+                .checked_sub(quote.amount_out)
+                .ok_or(QuoteError::Overflow)?,
+            real_sol_reserves: self
+                .real_sol_reserves
+                .checked_add(net_lamports)
+                .ok_or(QuoteError::Overflow)?,
+            real_token_reserves: self
+                .real_token_reserves
+                .checked_sub(quote.amount_out)
+                .ok_or(QuoteError::Overflow)?,
+            ..*self
+        })
+    }
+
+    //  state after a sell, the curve pays out the lamports plus fee
+    pub fn after_sell(&self, quote: &Quote) -> Result<CurveState> {
+        let payout = quote
+            .amount_out
+            .checked_add(quote.fee)
+            .ok_or(QuoteError::Overflow)?;
+
+        Ok(CurveState {
+            virtual_sol_reserves: self
+                .virtual_sol_reserves
+                .checked_sub(payout)
+                .ok_or(QuoteError::Overflow)?,
+            virtual_token_reserves: self
+                .virtual_token_reserves
+                .checked_add(quote.amount_in)
+                .ok_or(QuoteError::Overflow)?,
+            real_sol_reserves: self
+                .real_sol_reserves
+                .checked_sub(payout)
+                .ok_or(QuoteError::Overflow)?,
+            real_token_reserves: self
+                .real_token_reserves
+                .checked_add(quote.amount_in)
+                .ok_or(QuoteError::Overflow)?,
+            ..*self
+        })
+    }
+}
+
+//  a priced trade, fee is always in lamports
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Quote {
+    pub amount_in: u64,
+    pub amount_out: u64,
+    pub fee: u64,
+}
+
+//  buy with an exact amount of lamports, fee is taken from the lamports paid in
+pub fn quote_buy(state: &CurveState, lamports_in: u64, fee_bps: u16) -> Result<Quote> {
+    let fee = calc_fee(lamports_in, fee_bps)?;
+    let net_lamports = lamports_in.checked_sub(fee).ok_or(QuoteError::Overflow)?;
+
+    Ok(Quote {
+        amount_in: lamports_in,
+        amount_out: state.curve()?.buy_amount_out(net_lamports)?,
+        fee,
+    })
+}
+
+//  buy an exact amount of tokens, grossed up so the fee still leaves enough
+pub fn quote_buy_exact_out(state: &CurveState, tokens_out: u64, fee_bps: u16) -> Result<Quote> {
+    let net_lamports = state.curve()?.buy_amount_in(tokens_out)?;
+    let amount_in = gross_up(net_lamports, fee_bps)?;
+
+    Ok(Quote {
+        amount_in,
+        amount_out: tokens_out,
+        fee: calc_fee(amount_in, fee_bps)?,
+    })
+}
+
+//  sell an exact amount of tokens, fee is taken from the lamports paid out
+pub fn quote_sell(state: &CurveState, tokens_in: u64, fee_bps: u16) -> Result<Quote> {
+    let gross_lamports = state.curve()?.sell_amount_out(tokens_in)?;
+    let fee = calc_fee(gross_lamports, fee_bps)?;
+
+    Ok(Quote {
+        amount_in: tokens_in,
+        amount_out: gross_lamports
+            .checked_sub(fee)
+            .ok_or(QuoteError::Overflow)?,
+        fee,
+    })
+}
+
+//  sell for an exact amount of lamports, the curve pays out that plus the fee on it
+pub fn quote_sell_exact_out(state: &CurveState, lamports_out: u64, fee_bps: u16) -> Result<Quote> {
+    let gross_lamports = gross_up(lamports_out, fee_bps)?;
+
+    Ok(Quote {
+        amount_in: state.curve()?.sell_amount_in(gross_lamports)?,
+        amount_out: lamports_out,
+        fee: calc_fee(gross_lamports, fee_bps)?,
+    })
+}
+
+//  most tokens a buy can take before the curve completes,
+//  either all real tokens or enough to push real sol to the limit
+pub fn max_buy_amount_out(state: &CurveState, curve_limit: u64) -> Result<u64> {
+    let curve = state.curve()?;
+    let remaining_lamports = curve_limit.saturating_sub(state.real_sol_reserves);
+
+    //  one more token if the affordable amount stops short of the limit
+    let mut tokens_for_limit = curve.buy_amount_out(remaining_lamports)?;
+    if tokens_for_limit < state.real_token_reserves
+        && curve.buy_amount_in(tokens_for_limit)? < remaining_lamports
+    {
+        tokens_for_limit += 1;
+    }
+
+    Ok(state.real_token_reserves.min(tokens_for_limit))
+}
+
+//  lamports per one token (without decimal)
+pub fn spot_price(state: &CurveState) -> Result<u64> {
+    state.curve()?.spot_price()
+}
+
+//  lamports value of the whole token supply at the spot price
+pub fn market_cap(state: &CurveState, token_total_supply: u64) -> Result<u64> {
+    to_u64(mul_div(
+        spot_price(state)? as u128,
+        token_total_supply as u128,
+        10u128.pow(TOKEN_DECIMAL as u32),
+        false,
+    )?)
+}
@@ -0,0 +1,225 @@
+use proptest::prelude::*;
+use pump_quote::consts::{EXPONENTIAL_STEPS, MAX_FEE_BPS};
+use pump_quote::math::{decayed_fee_bps, tax_share};
+use pump_quote::{
+    quote_buy, quote_buy_exact_out, quote_sell, quote_sell_exact_out, CurveKind, CurveState,
+};
+
+const INITIAL_PRICE: u64 = 600;
+
+fn curve_kind() -> impl Strategy<Value = CurveKind> {
+    prop_oneof![
+        Just(CurveKind::ConstantProduct),
+        Just(CurveKind::Linear),
+        Just(CurveKind::Exponential),
+    ]
+}
+
+//  any state at all, consistent or not, to hunt for panics
+fn any_state() -> impl Strategy<Value = CurveState> {
+    (curve_kind(), any::<[u64; 6]>(), any::<u32>()).prop_map(|(kind, r, growth_bps)| CurveState {
+        kind,
+        virtual_sol_reserves: r[0],
+        virtual_token_reserves: r[1],
+        real_sol_reserves: r[2],
+        real_token_reserves: r[3],
+        initial_real_token_reserves: r[4],
+        start_price: r[5],
+        growth_bps,
+    })
+}
+
+//  a freshly launched curve with parameters in the range configure accepts
+fn launched_state() -> impl Strategy<Value = CurveState> {
+    (
+        curve_kind(),
+        1_000_000u64..=1_000_000_000_000,
+        1_000_000_000u64..=u64::MAX / 2,
+        0u64..=1_000,
+        1_000u32..=100_000,
+    )
+        .prop_flat_map(
+            |(kind, virtual_sol, virtual_token, real_permille, growth_bps)| {
+                let real_token = (virtual_token as u128 * real_permille as u128 / 1_000) as u64;
+                let real_token = real_token.max(EXPONENTIAL_STEPS);
+                let growth_bps = match kind {
+                    CurveKind::Exponential => growth_bps % 1_000,
+                    _ => growth_bps,
+                };
+
+                Just(CurveState {
+                    kind,
+                    virtual_sol_reserves: virtual_sol,
+                    virtual_token_reserves: virtual_token.max(real_token),
+                    real_sol_reserves: 0,
+                    real_token_reserves: real_token,
+                    initial_real_token_reserves: real_token,
+                    start_price: INITIAL_PRICE,
+                    growth_bps,
+                })
+            },
+        )
+}
+
+fn fee_bps() -> impl Strategy<Value = u16> {
+    0..MAX_FEE_BPS
+}
+
+fn k(state: &CurveState) -> u128 {
+    state.virtual_sol_reserves as u128 * state.virtual_token_reserves as u128
+}
+
+proptest! {
+    #[test]
+    fn no_overflow_panics(state in any_state(), amount in any::<u64>(), fee_bps in any::<u16>()) {
+        let _ = quote_buy(&state, amount, fee_bps);
+        let _ = quote_buy_exact_out(&state, amount, fee_bps);
+        let _ = quote_sell(&state, amount, fee_bps);
+        let _ = quote_sell_exact_out(&state, amount, fee_bps);
+        let _ = pump_quote::max_buy_amount_out(&state, amount);
+        let _ = pump_quote::spot_price(&state);
+        let _ = pump_quote::market_cap(&state, amount);
+    }
+
+    #[test]
+    fn fees_never_exceed_the_sol_side(state in any_state(), amount in any::<u64>(), fee_bps in fee_bps()) {
+        if let Ok(quote) = quote_buy(&state, amount, fee_bps) {
+            prop_assert!(quote.fee <= quote.amount_in);
+            prop_assert!(fee_bps == 0 || amount == 0 || quote.fee > 0);
+        }
+        if let Ok(quote) = quote_buy_exact_out(&state, amount, fee_bps) {
+            prop_assert!(quote.fee <= quote.amount_in);
+        }
+        if let Ok(quote) = quote_sell(&state, amount, fee_bps) {
+            prop_assert!(quote.amount_out.checked_add(quote.fee).is_some());
+        }
+        if let Ok(quote) = quote_sell_exact_out(&state, amount, fee_bps) {
+            prop_assert!(quote.amount_out.checked_add(quote.fee).is_some());
+        }
+    }
+
+    #[test]
+    fn integral_buys_never_exceed_the_supply_left(
+        state in launched_state(),
+        lamports_in in any::<u64>(),
+        fee_bps in fee_bps(),
+    ) {
+        if state.kind != CurveKind::ConstantProduct {
+            let quote = quote_buy(&state, lamports_in, fee_bps).unwrap();
+            prop_assert!(quote.amount_out <= state.real_token_reserves);
+        }
+    }
+
+    #[test]
+    fn k_never_decreases_on_buy(mut state in launched_state(), lamports_in in any::<u64>(), fee_bps in fee_bps()) {
+        state.kind = CurveKind::ConstantProduct;
+
+        if let Ok(quote) = quote_buy(&state, lamports_in, fee_bps) {
+            if let Ok(after) = state.after_buy(&quote) {
+                prop_assert!(k(&after) >= k(&state));
+            }
+        }
+        if let Ok(quote) = quote_buy_exact_out(&state, lamports_in, fee_bps) {
+            if let Ok(after) = state.after_buy(&quote) {
+                prop_assert!(k(&after) >= k(&state));
+            }
+        }
+    }
+
+    #[test]
+    fn k_never_decreases_on_sell(
+        mut state in launched_state(),
+        lamports_in in 1u64..=1_000_000_000_000,
+        amount in any::<u64>(),
+        fee_bps in fee_bps(),
+    ) {
+        state.kind = CurveKind::ConstantProduct;
+
+        //  put some sol on the curve first so there is something to sell into
+        let bought = quote_buy(&state, lamports_in, fee_bps).ok();
+        let Some(state) = bought.and_then(|quote| state.after_buy(&quote).ok()) else {
+            return Ok(());
+        };
+
+        if let Ok(quote) = quote_sell(&state, amount, fee_bps) {
+            if let Ok(after) = state.after_sell(&quote) {
+                prop_assert!(k(&after) >= k(&state));
+            }
+        }
+        if let Ok(quote) = quote_sell_exact_out(&state, amount, fee_bps) {
+            if let Ok(after) = state.after_sell(&quote) {
+                prop_assert!(k(&after) >= k(&state));
+            }
+        }
+    }
+
+    #[test]
+    fn buy_then_sell_never_profits(
+        state in launched_state(),
+        lamports_in in any::<u64>(),
+        buy_fee_bps in fee_bps(),
+        sell_fee_bps in fee_bps(),
+    ) {
+        let Ok(buy) = quote_buy(&state, lamports_in, buy_fee_bps) else {
+            return Ok(());
+        };
+        let Ok(after_buy) = state.after_buy(&buy) else {
+            return Ok(());
+        };
+
+        if let Ok(sell) = quote_sell(&after_buy, buy.amount_out, sell_fee_bps) {
+            prop_assert!(sell.amount_out <= buy.amount_in);
+
+            //  the curve can always pay back what it took in
+            prop_assert!(after_buy.after_sell(&sell).is_ok());
+        }
+    }
+
+    #[test]
+    fn exact_out_buy_then_sell_never_profits(
+        state in launched_state(),
+        tokens_out in any::<u64>(),
+        buy_fee_bps in fee_bps(),
+        sell_fee_bps in fee_bps(),
+    ) {
+        //  keep the request within what the curve holds
+        let tokens_out = tokens_out % (state.real_token_reserves + 1);
+
+        let Ok(buy) = quote_buy_exact_out(&state, tokens_out, buy_fee_bps) else {
+            return Ok(());
+        };
+        let Ok(after_buy) = state.after_buy(&buy) else {
+            return Ok(());
+        };
+
+        if let Ok(sell) = quote_sell(&after_buy, buy.amount_out, sell_fee_bps) {
+            prop_assert!(sell.amount_out <= buy.amount_in);
+            prop_assert!(after_buy.after_sell(&sell).is_ok());
+        }
+    }
+
+    #[test]
+    fn launch_tax_decays_to_the_base_fee(
+        base_bps in fee_bps(),
+        launch_tax_bps in fee_bps(),
+        decay_slots in 1..1_000_000u64,
+        elapsed in any::<u64>(),
+    ) {
+        let elapsed = elapsed % (decay_slots + 2);
+        let now = decayed_fee_bps(base_bps, launch_tax_bps, decay_slots, elapsed).unwrap();
+        let later = decayed_fee_bps(base_bps, launch_tax_bps, decay_slots, elapsed + 1).unwrap();
+
+        //  starts at the tax, never rises and ends at the base fee
+        prop_assert!(now >= base_bps && now <= base_bps.max(launch_tax_bps));
+        prop_assert!(later <= now);
+        prop_assert_eq!(decayed_fee_bps(base_bps, launch_tax_bps, decay_slots, 0).unwrap(), base_bps.max(launch_tax_bps));
+        prop_assert_eq!(decayed_fee_bps(base_bps, launch_tax_bps, decay_slots, decay_slots).unwrap(), base_bps);
+    }
+
+    #[test]
+    fn launch_tax_is_part_of_the_fee(fee in any::<u64>(), base_bps in fee_bps(), fee_bps in fee_bps()) {
+        let tax = tax_share(fee, base_bps, fee_bps).unwrap();
+        prop_assert!(tax <= fee);
+        prop_assert!(fee_bps > base_bps || tax == 0);
+    }
+}
@@ -105,7 +105,7 @@ export const swapTx = async (
   const configAccount = await program.account.config.fetch(configPda);
 
   const tx = await program.methods
-    .swap(new BN(amount), style, new BN(amount))
+    .swap(new BN(amount), style, new BN(amount), null, null, false)
     .accounts({
       teamWallet: configAccount.teamWallet,
       user,
@@ -0,0 +1,108 @@
+//! Example of routing a buy through pump by CPI.
+//!
+//! A system owned vault PDA of this program holds the SOL, signs the swap and
+//! receives the tokens. Fund the vault by transferring SOL to its address.
+
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token},
+};
+use pump::program::Pump;
+
+declare_id!("D1qvfghjNWP28EBP9GurDrwucKZiCPV3e6X92k5vqsAX");
+
+#[program]
+pub mod pump_cpi_example {
+    use super::*;
+
+    //  buy on a pump curve with SOL held by the caller's vault
+    pub fn buy(ctx: Context<BuyFromVault>, lamports_in: u64, min_tokens_out: u64) -> Result<()> {
+        ctx.accounts
+            .process(lamports_in, min_tokens_out, ctx.bumps.vault)
+    }
+}
+
+#[derive(Accounts)]
+pub struct BuyFromVault<'info> {
+    authority: Signer<'info>,
+
+    //  no data, so the system program can debit it for the buy
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, authority.key().as_ref()],
+        bump
+    )]
+    vault: SystemAccount<'info>,
+
+    //  pump accounts, validated by pump itself
+    /// CHECK: pump global config PDA
+    global_config: UncheckedAccount<'info>,
+    /// CHECK: pump fee recipient
+    #[account(mut)]
+    fee_recipient: UncheckedAccount<'info>,
+    /// CHECK: pump tax treasury
+    #[account(mut)]
+    tax_treasury: UncheckedAccount<'info>,
+    /// CHECK: pump bonding curve PDA
+    #[account(mut)]
+    bonding_curve: UncheckedAccount<'info>,
+    /// CHECK: pump creator vault PDA of the curve creator
+    #[account(mut)]
+    creator_vault: UncheckedAccount<'info>,
+    token_mint: Box<Account<'info, Mint>>,
+    /// CHECK: token account of the bonding curve
+    #[account(mut)]
+    curve_token_account: UncheckedAccount<'info>,
+    /// CHECK: token account of the vault, created by pump if missing
+    #[account(mut)]
+    vault_token_account: UncheckedAccount<'info>,
+    /// CHECK: pump event authority PDA
+    event_authority: UncheckedAccount<'info>,
+
+    token_program: Program<'info, Token>,
+    associated_token_program: Program<'info, AssociatedToken>,
+    system_program: Program<'info, System>,
+    pump_program: Program<'info, Pump>,
+}
+
+pub const VAULT_SEED: &[u8] = b"vault";
+
+impl<'info> BuyFromVault<'info> {
+    pub fn process(&self, lamports_in: u64, min_tokens_out: u64, bump_vault: u8) -> Result<()> {
+        let authority = self.authority.key();
+        let signer: &[&[&[u8]]] = &[&[VAULT_SEED, authority.as_ref(), &[bump_vault]]];
+
+        let ctx = CpiContext::new_with_signer(
+            self.pump_program.to_account_info(),
+            pump::cpi::accounts::Swap {
+                user: self.vault.to_account_info(),
+                global_config: self.global_config.to_account_info(),
+                fee_recipient: self.fee_recipient.to_account_info(),
+                tax_treasury: self.tax_treasury.to_account_info(),
+                bonding_curve: self.bonding_curve.to_account_info(),
+                creator_vault: self.creator_vault.to_account_info(),
+                token_mint: self.token_mint.to_account_info(),
+                curve_token_account: self.curve_token_account.to_account_info(),
+                user_token_account: self.vault_token_account.to_account_info(),
+                token_program: self.token_program.to_account_info(),
+                associated_token_program: self.associated_token_program.to_account_info(),
+                system_program: self.system_program.to_account_info(),
+                event_authority: self.event_authority.to_account_info(),
+                program: self.pump_program.to_account_info(),
+            },
+            signer,
+        );
+
+        //  a buy crossing the curve limit fills partially, so use the returned amounts
+        let filled = pump::client::buy(ctx, lamports_in, min_tokens_out, None, None, false)?;
+        msg!(
+            "Bought {} tokens for {} lamports, fee {}",
+            filled.amount_out,
+            filled.amount_in,
+            filled.fee
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,132 @@
+//! CPI client for routing trades through pump curves from another program.
+//!
+//! Enabled by the `cpi` feature:
+//!
+//! ```toml
+//! pump = { path = "../pump", features = ["cpi"] }
+//! ```
+//!
+//! Every helper takes a [`CpiContext`] over [`Swap`], the same accounts as the
+//! `swap` instruction, in this order:
+//!
+//! | account                    | notes                                                      |
+//! |----------------------------|------------------------------------------------------------|
+//! | `user`                     | signer, pays SOL on a buy and rent of its token account    |
+//! | `global_config`            | `["global-config"]` PDA of pump                            |
+//! | `fee_recipient`            | writable, must match `global_config.fee_recipient`         |
+//! | `tax_treasury`             | writable, must match `global_config.tax_treasury`          |
+//! | `bonding_curve`            | writable, `["bonding-curve", mint]` PDA of pump            |
+//! | `creator_vault`            | writable, `["creator-vault", bonding_curve.creator]` PDA of pump |
+//! | `token_mint`               |                                                            |
+//! | `curve_token_account`      | writable, associated token account of the bonding curve    |
+//! | `user_token_account`       | writable, associated token account of `user`, created if missing |
+//! | `token_program`            |                                                            |
+//! | `associated_token_program` |                                                            |
+//! | `system_program`           |                                                            |
+//! | `event_authority`          | `["__event_authority"]` PDA of pump, signs event self-CPIs |
+//! | `program`                  | the pump program itself                                    |
+//!
+//! During a curve's presale, buys need the wallet's [`PresaleProof`] and the
+//! `["presale-position", mint, user]` PDA of pump, writable, as the first remaining account.
+//! Sells never need a proof.
+//!
+//! For `Config.early_window_slots` slots after public trading opens, buys pass
+//! `early_buy` and the `["early-buy-position", mint, user]` PDA of pump, writable, right after
+//! the presale position. Outside the window a passed position is ignored.
+//!
+//! To credit a referrer, add `[referrer wallet, ["referral-stats", referrer] PDA of pump]`,
+//! both writable, with [`CpiContext::with_remaining_accounts`].
+//!
+//! When `user` is a PDA of the calling program, build the context with
+//! [`CpiContext::new_with_signer`]. A PDA paying for a buy must be owned by the
+//! system program and hold no data, so the system program can debit it.
+//!
+//! Each helper returns the filled [`SwapResult`], read back from the return data
+//! pump sets. A buy that crosses the curve limit only partially fills, so check
+//! `amount_in` rather than assuming the whole input was spent.
+
+use anchor_lang::prelude::*;
+
+use crate::cpi::accounts::Swap;
+use crate::states::{PresaleProof, SwapDirection};
+use crate::SwapResult;
+
+/// Buy tokens with exactly `lamports_in` (fee included), receiving at least `min_tokens_out`.
+pub fn buy<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, Swap<'info>>,
+    lamports_in: u64,
+    min_tokens_out: u64,
+    deadline: Option<i64>,
+    presale: Option<PresaleProof>,
+    early_buy: bool,
+) -> Result<SwapResult> {
+    Ok(crate::cpi::swap(
+        ctx,
+        lamports_in,
+        SwapDirection::Buy.into(),
+        min_tokens_out,
+        deadline,
+        presale,
+        early_buy,
+    )?
+    .get())
+}
+
+/// Sell exactly `tokens_in`, receiving at least `min_lamports_out` after the fee.
+pub fn sell<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, Swap<'info>>,
+    tokens_in: u64,
+    min_lamports_out: u64,
+    deadline: Option<i64>,
+) -> Result<SwapResult> {
+    Ok(crate::cpi::swap(
+        ctx,
+        tokens_in,
+        SwapDirection::Sell.into(),
+        min_lamports_out,
+        deadline,
+        None,
+        false,
+    )?
+    .get())
+}
+
+/// Buy exactly `tokens_out`, paying at most `max_lamports_in` (fee included).
+pub fn buy_exact_out<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, Swap<'info>>,
+    tokens_out: u64,
+    max_lamports_in: u64,
+    deadline: Option<i64>,
+    presale: Option<PresaleProof>,
+    early_buy: bool,
+) -> Result<SwapResult> {
+    Ok(crate::cpi::swap_exact_out(
+        ctx,
+        tokens_out,
+        SwapDirection::Buy.into(),
+        max_lamports_in,
+        deadline,
+        presale,
+        early_buy,
+    )?
+    .get())
+}
+
+/// Sell for exactly `lamports_out` after the fee, spending at most `max_tokens_in`.
+pub fn sell_exact_out<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, Swap<'info>>,
+    lamports_out: u64,
+    max_tokens_in: u64,
+    deadline: Option<i64>,
+) -> Result<SwapResult> {
+    Ok(crate::cpi::swap_exact_out(
+        ctx,
+        lamports_out,
+        SwapDirection::Sell.into(),
+        max_tokens_in,
+        deadline,
+        None,
+        false,
+    )?
+    .get())
+}
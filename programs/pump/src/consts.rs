@@ -1,2 +1,4 @@
+pub use pump_quote::consts::{BPS_DENOMINATOR, EXPONENTIAL_STEPS, MAX_FEE_BPS, TOKEN_DECIMAL};
+pub use anchor_spl::metadata::mpl_token_metadata::{MAX_NAME_LENGTH, MAX_SYMBOL_LENGTH, MAX_URI_LENGTH};
+
 pub const INITIAL_PRICE: u64 = 600;       // lamports per one token (without decimal)
-pub const TOKEN_DECIMAL: u8 = 6;          // token decimal
@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
+use pump_quote::QuoteError;
 
+//  clients match on the error codes, new variants go at the end
 #[error_code]
 pub enum PumpError {
     #[msg("Not authorized address")]
@@ -25,4 +27,70 @@ pub enum PumpError {
 
     #[msg("Program is completed")]
     ProgramCompleted,
+
+    #[msg("Amount in is larger than allowed amount")]
+    InputAmountTooLarge,
+
+    #[msg("Not enough tokens left on the curve")]
+    NotEnoughTokenReserves,
+
+    #[msg("Not enough SOL left on the curve")]
+    NotEnoughSolReserves,
+
+    #[msg("Curve kind is not allowed by the config")]
+    CurveKindNotAllowed,
+
+    #[msg("Transaction deadline has passed")]
+    DeadlineExceeded,
+
+    #[msg("Referrer accounts are missing or do not match")]
+    InvalidReferrer,
+
+    #[msg("Metadata account is not the metadata PDA of the mint")]
+    InvalidMetadataAccount,
+
+    #[msg("Token name is empty, too long or has control characters")]
+    InvalidTokenName,
+
+    #[msg("Token symbol is empty, too long or not ASCII alphanumeric")]
+    InvalidTokenSymbol,
+
+    #[msg("Token URI is empty, too long or has spaces or non-ASCII characters")]
+    InvalidTokenUri,
+
+    #[msg("Trading on this curve has not started yet")]
+    TradingNotStarted,
+
+    #[msg("Trading on this curve has already started")]
+    TradingAlreadyStarted,
+
+    #[msg("Presale buys need a valid allowlist proof")]
+    NotOnPresaleAllowlist,
+
+    #[msg("Presale position account is missing or does not match")]
+    InvalidPresaleAccount,
+
+    #[msg("Buy goes over the wallet's presale cap")]
+    PresaleCapExceeded,
+
+    #[msg("Early buy position account is missing or does not match")]
+    InvalidEarlyBuyAccount,
+
+    #[msg("Buy goes over the per-wallet cap of the early trading window")]
+    EarlyBuyCapExceeded,
+
+    #[msg("Tax treasury address does not match the one in the config")]
+    IncorrectTaxTreasury,
+
+    #[msg("Swap direction must be buy or sell")]
+    InvalidSwapDirection,
+}
+
+impl From<QuoteError> for PumpError {
+    fn from(error: QuoteError) -> Self {
+        match error {
+            QuoteError::Overflow => PumpError::OverflowOrUnderflowOccurred,
+            QuoteError::InvalidParameter => PumpError::IncorrectValue,
+        }
+    }
 }
@@ -0,0 +1,55 @@
+use crate::{
+    errors::PumpError,
+    states::{Config, CreatorVault},
+    utils::{ensure_not_paused, sol_transfer_from_pda},
+};
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct CreatorFeesClaimed {
+    pub creator: Pubkey,
+    pub lamports: u64,
+    pub timestamp: i64,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimCreatorFees<'info> {
+    #[account(mut)]
+    creator: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED_PREFIX.as_bytes()],
+        bump,
+    )]
+    global_config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        seeds = [CreatorVault::SEED_PREFIX.as_bytes(), creator.key().as_ref()],
+        bump,
+        has_one = creator @ PumpError::NotAuthorized
+    )]
+    creator_vault: Box<Account<'info, CreatorVault>>,
+}
+
+impl<'info> ClaimCreatorFees<'info> {
+    //  returns the event for the handler to emit through a self-CPI
+    pub fn process(&mut self) -> Result<CreatorFeesClaimed> {
+        // global guards
+        ensure_not_paused(&self.global_config)?;
+
+        //  everything above rent exemption is claimable
+        let vault = self.creator_vault.to_account_info();
+        let min_balance = Rent::get()?.minimum_balance(vault.data_len());
+        let lamports = vault.lamports().saturating_sub(min_balance);
+
+        sol_transfer_from_pda(&vault, &self.creator.to_account_info(), lamports)?;
+
+        Ok(CreatorFeesClaimed {
+            creator: self.creator.key(),
+            lamports,
+            timestamp: Clock::get()?.unix_timestamp,
+        })
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{errors::PumpError, states::Config, utils::{ensure_admin}};
+use crate::{states::Config, utils::ensure_admin};
 use anchor_lang::{prelude::*, system_program};
 
 #[derive(Accounts)]
@@ -23,9 +23,10 @@ impl<'info> Configure<'info> {
     pub fn process(&mut self, new_config: Config) -> Result<()> {
         // Admin-only (allow first-time init when authority is default)
         if !self.global_config.authority.eq(&Pubkey::default()) {
-            ensure_admin(&self.global_config.as_ref(), &self.admin.key())?;
+            ensure_admin(self.global_config.as_ref(), &self.admin.key())?;
         }
 
+        new_config.validate()?;
         self.global_config.set_inner(new_config);
 
         Ok(())
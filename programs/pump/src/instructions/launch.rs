@@ -1,15 +1,53 @@
 use crate::{
     consts::TOKEN_DECIMAL,
-    states::{BondingCurve, Config},
-    utils::{ensure_not_completed, ensure_not_paused},
+    errors::PumpError,
+    instructions::{CurveCompletedEvent, TradeEvent},
+    states::{
+        BondingCurve, CappedPosition, Config, CreatorVault, CurveKind, LaunchFeeExemption,
+        PositionKind, PresaleParams, SwapDirection, SwapMode,
+    },
+    utils::{
+        ensure_before_deadline, ensure_not_completed, ensure_not_paused, ensure_valid_metadata,
+        sol_transfer_from_user,
+    },
 };
 use anchor_lang::{prelude::*, solana_program::sysvar::SysvarId, system_program};
 use anchor_spl::{
-    associated_token::{self, AssociatedToken},
-    metadata::{self, mpl_token_metadata::types::DataV2, Metadata},
+    associated_token::{self, get_associated_token_address, AssociatedToken},
+    metadata::{
+        self,
+        mpl_token_metadata::{accounts::Metadata as MetadataAccount, types::DataV2},
+        Metadata,
+    },
     token::{self, spl_token::instruction::AuthorityType, Mint, Token, TokenAccount},
 };
 
+#[event]
+pub struct LaunchEvent {
+    pub mint: Pubkey,
+    pub creator: Pubkey,
+    pub bonding_curve: Pubkey,
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub curve_kind: CurveKind,
+    //  reserves the curve starts trading from
+    pub virtual_sol_reserves: u64,
+    pub virtual_token_reserves: u64,
+    pub real_token_reserves: u64,
+    pub token_total_supply: u64,
+    pub trading_starts_at: i64,
+    pub presale_ends_at: i64,
+    //  lamports paid to the fee recipient, zero for exempt creators
+    pub launch_fee: u64,
+    pub slot: u64,
+    pub timestamp: i64,
+}
+
+//  events of the optional creator dev buy
+pub type DevBuyEvents = (TradeEvent, Option<CurveCompletedEvent>);
+
+#[event_cpi]
 #[derive(Accounts)]
 pub struct Launch<'info> {
     #[account(mut)]
@@ -20,6 +58,18 @@ pub struct Launch<'info> {
         bump,
     )]
     global_config: Box<Account<'info, Config>>,
+    /// CHECK: should be same with the address in the global_config
+    #[account(
+        mut,
+        constraint = global_config.fee_recipient == fee_recipient.key() @PumpError::IncorrectFeeRecipient
+    )]
+    fee_recipient: AccountInfo<'info>,
+    /// CHECK: should be same with the tax treasury in the global_config
+    #[account(
+        mut,
+        constraint = global_config.tax_treasury == tax_treasury.key() @PumpError::IncorrectTaxTreasury
+    )]
+    tax_treasury: AccountInfo<'info>,
 
     #[account(
         init,
@@ -48,8 +98,42 @@ pub struct Launch<'info> {
     )]
     curve_token_account: Box<Account<'info, TokenAccount>>,
 
+    //  created on the creator's first launch, shared by all of their curves
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = 8 + CreatorVault::LEN,
+        seeds = [CreatorVault::SEED_PREFIX.as_bytes(), creator.key().as_ref()],
+        bump
+    )]
+    creator_vault: Box<Account<'info, CreatorVault>>,
+
+    /// CHECK: admin-managed exemption from the launch fee, may not exist
+    #[account(
+        seeds = [LaunchFeeExemption::SEED_PREFIX.as_bytes(), creator.key().as_ref()],
+        bump
+    )]
+    launch_fee_exemption: UncheckedAccount<'info>,
+
+    /// CHECK: creator's token account, only created when launching with a dev buy
+    #[account(
+        mut,
+        address = get_associated_token_address(&creator.key(), &token_mint.key())
+    )]
+    creator_token_account: UncheckedAccount<'info>,
+
+    /// CHECK: creator's early buy position, only created by a dev buy when the config has an early window
+    #[account(
+        mut,
+        address = CappedPosition::address(PositionKind::EarlyBuy, &token_mint.key(), &creator.key()).0 @ PumpError::InvalidEarlyBuyAccount
+    )]
+    creator_early_buy_position: UncheckedAccount<'info>,
+
     /// CHECK: initialized by token metadata program
-    #[account(mut)]
+    #[account(
+        mut,
+        address = MetadataAccount::find_pda(&token_mint.key()).0 @ PumpError::InvalidMetadataAccount
+    )]
     token_metadata_account: UncheckedAccount<'info>,
 
     #[account(address = token::ID)]
@@ -73,11 +157,48 @@ impl<'info> Launch<'info> {
         symbol: String,
         uri: String,
 
+        //  pricing family of the curve
+        curve_kind: CurveKind,
+
+        //  unix timestamp trading opens at, defaults to now
+        trading_starts_at: Option<i64>,
+        //  optional allowlisted window from the trading start, public trading opens after it
+        presale: Option<PresaleParams>,
+
+        //  optional creator dev buy, filled before anyone else can trade
+        initial_buy_lamports: Option<u64>,
+        min_tokens_out: u64,
+        deadline: Option<i64>, //  only checked with a dev buy
+
         bump_config: u8,
-    ) -> Result<()> { 
+        bump_bonding_curve: u8,
+    ) -> Result<(LaunchEvent, Option<DevBuyEvents>)> {
         // global guards
-        ensure_not_paused(&self.global_config.as_ref())?;
-        ensure_not_completed(&self.global_config.as_ref())?;
+        ensure_not_paused(self.global_config.as_ref())?;
+        ensure_not_completed(self.global_config.as_ref())?;
+        require!(
+            self.global_config.is_curve_kind_allowed(curve_kind),
+            PumpError::CurveKindNotAllowed
+        );
+        ensure_valid_metadata(&self.global_config, &name, &symbol, &uri)?;
+        let clock = Clock::get()?;
+        self.creator_vault.creator = self.creator.key();
+
+        //  flat launch fee, unless the admin exempted this creator
+        let launch_fee = if LaunchFeeExemption::is_exempt(&self.launch_fee_exemption) {
+            0
+        } else {
+            self.global_config.launch_fee_lamports
+        };
+        if launch_fee > 0 {
+            sol_transfer_from_user(
+                &self.creator,
+                &self.fee_recipient,
+                &self.system_program.to_account_info(),
+                launch_fee,
+            )?;
+        }
+
         let bonding_curve = &mut self.bonding_curve;
         let global_config = &self.global_config;
 
@@ -88,6 +209,21 @@ impl<'info> Launch<'info> {
         bonding_curve.real_sol_reserves = 0;
         bonding_curve.token_total_supply = global_config.total_token_supply;
         bonding_curve.is_completed = false;
+        bonding_curve.creator = self.creator.key();
+        bonding_curve.curve_kind = curve_kind;
+        bonding_curve.curve_growth_bps = global_config.curve_growth_bps(curve_kind);
+        bonding_curve.initial_real_token_reserves = global_config.initial_real_token_reserves;
+        bonding_curve.trading_starts_at = trading_starts_at.unwrap_or(clock.unix_timestamp);
+        if let Some(presale) = presale {
+            require!(
+                presale.ends_at > bonding_curve.trading_starts_at,
+                PumpError::IncorrectValue
+            );
+            bonding_curve.presale_ends_at = presale.ends_at;
+            bonding_curve.presale_merkle_root = presale.merkle_root;
+        }
+        //  a curve that opens right away starts its early window at launch
+        bonding_curve.mark_opened(clock.unix_timestamp, clock.slot);
 
         ////////////////////////////////////////////////////////////////////////////////
         //  move the below to swap ix if you want the first buyer to pays the other fee
@@ -152,6 +288,112 @@ impl<'info> Launch<'info> {
             None,
         )?;
 
-        Ok(())
+        //  reserves before the optional dev buy
+        let launched = LaunchEvent {
+            mint: self.token_mint.key(),
+            creator: self.creator.key(),
+            bonding_curve: self.bonding_curve.key(),
+            name,
+            symbol,
+            uri,
+            curve_kind,
+            virtual_sol_reserves: self.bonding_curve.virtual_sol_reserves,
+            virtual_token_reserves: self.bonding_curve.virtual_token_reserves,
+            real_token_reserves: self.bonding_curve.real_token_reserves,
+            token_total_supply: self.bonding_curve.token_total_supply,
+            trading_starts_at: self.bonding_curve.trading_starts_at,
+            presale_ends_at: self.bonding_curve.presale_ends_at,
+            launch_fee,
+            slot: clock.slot,
+            timestamp: clock.unix_timestamp,
+        };
+
+        let dev_buy = match initial_buy_lamports {
+            Some(lamports) => Some(self.dev_buy(lamports, min_tokens_out, deadline, bump_bonding_curve)?),
+            None => None,
+        };
+
+        Ok((launched, dev_buy))
+    }
+
+    //  creator's first buy, through the same curve math and fee path as swap
+    fn dev_buy(
+        &mut self,
+        lamports: u64,
+        min_tokens_out: u64,
+        deadline: Option<i64>,
+        bump_bonding_curve: u8,
+    ) -> Result<DevBuyEvents> {
+        let clock = Clock::get()?;
+        ensure_before_deadline(deadline, clock.unix_timestamp)?;
+
+        associated_token::create_idempotent(CpiContext::new(
+            self.associated_token_program.to_account_info(),
+            associated_token::Create {
+                payer: self.creator.to_account_info(),
+                associated_token: self.creator_token_account.to_account_info(),
+                authority: self.creator.to_account_info(),
+                mint: self.token_mint.to_account_info(),
+                system_program: self.system_program.to_account_info(),
+                token_program: self.token_program.to_account_info(),
+            },
+        ))?;
+
+        let bonding_curve = &mut self.bonding_curve;
+        let curve_pda = &mut bonding_curve.to_account_info();
+        let global_config = &self.global_config;
+        //  no slot of public trading has passed yet, the dev buy pays the whole launch tax
+        let fee_bps = global_config.buy_fee_bps_at(0)?;
+
+        let (quote, fees) = bonding_curve.buy(
+            &self.token_mint,
+            global_config.curve_limit,
+            &self.creator,
+            curve_pda,
+            &mut self.fee_recipient,
+            &mut self.creator_vault.to_account_info(),
+            None,
+            Some(&self.tax_treasury),
+            &mut self.creator_token_account.to_account_info(),
+            &mut self.curve_token_account.to_account_info(),
+            SwapMode::ExactIn,
+            lamports,
+            min_tokens_out,
+            fee_bps,
+            global_config.buy_fee_bps,
+            global_config.creator_fee_bps,
+            0,
+            bump_bonding_curve,
+            &self.system_program.to_account_info(),
+            &self.token_program.to_account_info(),
+        )?;
+
+        //  the dev buy lands before or as public trading opens, so whenever the config
+        //  has an early window it counts against the creator's early buy cap
+        if global_config.early_window_slots > 0 {
+            CappedPosition::track(
+                PositionKind::EarlyBuy,
+                &self.creator_early_buy_position,
+                &self.token_mint.key(),
+                &self.creator.to_account_info(),
+                &self.system_program.to_account_info(),
+                quote.amount_out,
+                global_config.early_wallet_cap,
+            )?;
+        }
+
+        let trade = TradeEvent::new(
+            self.token_mint.key(),
+            self.creator.key(),
+            SwapDirection::Buy,
+            &quote,
+            &fees,
+            bonding_curve,
+            &clock,
+        );
+        let completed =
+            CurveCompletedEvent::new(self.token_mint.key(), self.creator.key(), bonding_curve, &clock);
+
+        Ok((trade, completed))
     }
 }
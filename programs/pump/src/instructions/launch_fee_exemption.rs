@@ -0,0 +1,68 @@
+use crate::{
+    states::{Config, LaunchFeeExemption},
+    utils::ensure_admin,
+};
+use anchor_lang::{prelude::*, system_program};
+
+#[derive(Accounts)]
+#[instruction(creator: Pubkey)]
+pub struct AddLaunchFeeExemption<'info> {
+    #[account(mut)]
+    admin: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED_PREFIX.as_bytes()],
+        bump,
+    )]
+    global_config: Box<Account<'info, Config>>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + LaunchFeeExemption::LEN,
+        seeds = [LaunchFeeExemption::SEED_PREFIX.as_bytes(), creator.as_ref()],
+        bump
+    )]
+    launch_fee_exemption: Box<Account<'info, LaunchFeeExemption>>,
+
+    #[account(address = system_program::ID)]
+    system_program: Program<'info, System>,
+}
+
+impl<'info> AddLaunchFeeExemption<'info> {
+    pub fn process(&mut self, creator: Pubkey) -> Result<()> {
+        ensure_admin(&self.global_config, &self.admin.key())?;
+
+        self.launch_fee_exemption.creator = creator;
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+#[instruction(creator: Pubkey)]
+pub struct RemoveLaunchFeeExemption<'info> {
+    #[account(mut)]
+    admin: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED_PREFIX.as_bytes()],
+        bump,
+    )]
+    global_config: Box<Account<'info, Config>>,
+
+    //  rent goes back to the admin
+    #[account(
+        mut,
+        close = admin,
+        seeds = [LaunchFeeExemption::SEED_PREFIX.as_bytes(), creator.as_ref()],
+        bump
+    )]
+    launch_fee_exemption: Box<Account<'info, LaunchFeeExemption>>,
+}
+
+impl<'info> RemoveLaunchFeeExemption<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        ensure_admin(&self.global_config, &self.admin.key())
+    }
+}
@@ -13,9 +13,9 @@ pub struct Migrate<'info> {
 impl<'info> Migrate<'info> {
     pub fn process(&mut self, _nonce: u8) -> Result<()> {
         // Only admin may migrate and only when not paused and not completed
-        ensure_not_paused(&self.global_config.as_ref())?;
-        ensure_not_completed(&self.global_config.as_ref())?;
-        ensure_admin(&self.global_config.as_ref(), &self.payer.key())?;
+        ensure_not_paused(self.global_config.as_ref())?;
+        ensure_not_completed(self.global_config.as_ref())?;
+        ensure_admin(self.global_config.as_ref(), &self.payer.key())?;
 
         ////////////////////    DM if you want full implementation    ////////////////////
         // telegram - https://t.me/microgift88
@@ -0,0 +1,71 @@
+use crate::{
+    errors::PumpError,
+    states::{Config, LegacyConfig},
+};
+use anchor_lang::{prelude::*, system_program};
+
+#[derive(Accounts)]
+pub struct MigrateConfig<'info> {
+    #[account(mut)]
+    admin: Signer<'info>,
+
+    /// CHECK: legacy layout can't be deserialized as `Config`, checked in process
+    #[account(
+        mut,
+        seeds = [Config::SEED_PREFIX.as_bytes()],
+        bump,
+    )]
+    global_config: UncheckedAccount<'info>,
+
+    #[account(address = system_program::ID)]
+    system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateConfig<'info> {
+    //  rewrite the legacy f64 fee layout of the global config as basis points
+    pub fn process(&mut self) -> Result<()> {
+        let config_info = self.global_config.to_account_info();
+
+        require_keys_eq!(*config_info.owner, crate::ID, PumpError::IncorrectValue);
+
+        let config = LegacyConfig::migrate(&config_info.try_borrow_data()?)?;
+
+        //  admin-only
+        require_keys_eq!(config.authority, self.admin.key(), PumpError::NotAuthorized);
+
+        let new_len = 8 + Config::LEN;
+        let min_balance = Rent::get()?.minimum_balance(new_len);
+        if new_len > config_info.data_len() {
+            //  grow to the new layout, the admin pays the rent the larger account needs
+            let top_up = min_balance.saturating_sub(config_info.lamports());
+            if top_up > 0 {
+                system_program::transfer(
+                    CpiContext::new(
+                        self.system_program.to_account_info(),
+                        system_program::Transfer {
+                            from: self.admin.to_account_info(),
+                            to: config_info.clone(),
+                        },
+                    ),
+                    top_up,
+                )?;
+            }
+            config_info.realloc(new_len, false)?;
+        } else {
+            //  shrink to the new layout and return the freed rent to the admin
+            config_info.realloc(new_len, false)?;
+
+            let excess = config_info.lamports().saturating_sub(min_balance);
+            if excess > 0 {
+                **config_info.try_borrow_mut_lamports()? -= excess;
+                **self.admin.to_account_info().try_borrow_mut_lamports()? += excess;
+            }
+        }
+
+        let mut data = config_info.try_borrow_mut_data()?;
+        let mut writer: &mut [u8] = &mut data[..];
+        config.try_serialize(&mut writer)?;
+
+        Ok(())
+    }
+}
@@ -1,9 +1,19 @@
 pub mod configure;
 pub use configure::*;
+pub mod migrate_config;
+pub use migrate_config::*;
 pub mod launch;
 pub use launch::*;
+pub mod launch_fee_exemption;
+pub use launch_fee_exemption::*;
+pub mod set_trading_start;
+pub use set_trading_start::*;
 pub mod swap;
 pub use swap::*;
+pub mod quote;
+pub use quote::*;
+pub mod claim_creator_fees;
+pub use claim_creator_fees::*;
 pub mod migrate;
 pub use migrate::*;
 pub mod release_reserves;
@@ -0,0 +1,67 @@
+use crate::{
+    errors::PumpError,
+    states::{BondingCurve, Config, SwapDirection},
+    utils::{ensure_not_completed, ensure_not_paused},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+//  preview of an exact in swap, returned through return data
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SwapQuote {
+    pub amount_in: u64,   //  less than requested when a buy only partially fills
+    pub amount_out: u64,  //  tokens on buy, lamports on sell
+    pub fee: u64,         //  lamports
+    pub price_after: u64, //  lamports per one token (without decimal) after the trade
+}
+
+#[derive(Accounts)]
+pub struct Quote<'info> {
+    #[account(
+        seeds = [Config::SEED_PREFIX.as_bytes()],
+        bump,
+    )]
+    global_config: Box<Account<'info, Config>>,
+
+    token_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        seeds = [BondingCurve::SEED_PREFIX.as_bytes(), &token_mint.key().to_bytes()],
+        bump
+    )]
+    bonding_curve: Box<Account<'info, BondingCurve>>,
+}
+
+impl<'info> Quote<'info> {
+    pub fn process(&self, amount: u64, direction: SwapDirection) -> Result<SwapQuote> {
+        //  fail the same way a swap would
+        ensure_not_paused(&self.global_config)?;
+        ensure_not_completed(&self.global_config)?;
+        require!(
+            !self.bonding_curve.is_completed,
+            PumpError::CurveAlreadyCompleted
+        );
+
+        //  buys include the launch tax at the current slot
+        let fee_bps = match direction {
+            SwapDirection::Buy => self
+                .global_config
+                .buy_fee_bps_at(self.bonding_curve.slots_since_open(Clock::get()?.slot))?,
+            SwapDirection::Sell => self.global_config.sell_fee_bps,
+        };
+        let (trade, after) = self.bonding_curve.preview(
+            direction,
+            amount,
+            self.token_mint.decimals,
+            fee_bps,
+            self.global_config.curve_limit,
+        )?;
+
+        Ok(SwapQuote {
+            amount_in: trade.amount_in,
+            amount_out: trade.amount_out,
+            fee: trade.fee,
+            price_after: pump_quote::spot_price(&after).map_err(PumpError::from)?,
+        })
+    }
+}
@@ -1,7 +1,7 @@
 use crate::{states::{BondingCurve, Config}, utils::{ensure_admin, ensure_not_paused}};
-use anchor_lang::{prelude::*, system_program};
+use anchor_lang::prelude::*;
 use anchor_lang::solana_program::sysvar::rent::Rent;
-use anchor_spl::associated_token::{self, AssociatedToken};
+use anchor_spl::associated_token::AssociatedToken;
 use anchor_spl::token::{self, CloseAccount, TransferChecked, Mint, Token, TokenAccount};
 
 #[event]
@@ -12,6 +12,7 @@ pub struct ReservesReleased {
     pub tokens_sent: u64,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct ReleaseReserves<'info> {
     #[account(mut, address = global_config.authority)]
@@ -63,10 +64,11 @@ pub enum ReleaseReservesError {
 }
 
 impl<'info> ReleaseReserves<'info> {
-    pub fn process(&mut self, bump_bonding_curve: u8) -> Result<()> {
+    //  returns the event for the handler to emit through a self-CPI
+    pub fn process(&mut self, bump_bonding_curve: u8) -> Result<ReservesReleased> {
         // global pause guard and admin check
-        ensure_not_paused(&self.global_config.as_ref())?;
-        ensure_admin(&self.global_config.as_ref(), &self.admin.key())?;
+        ensure_not_paused(self.global_config.as_ref())?;
+        ensure_admin(self.global_config.as_ref(), &self.admin.key())?;
         // ensure curve completed
         require!(self.bonding_curve.is_completed, ReleaseReservesError::CurveNotCompleted);
 
@@ -135,14 +137,12 @@ impl<'info> ReleaseReserves<'info> {
 
         // close ATA disabled in minimal patch
 
-        emit!(ReservesReleased {
+        Ok(ReservesReleased {
             mint: self.token_mint.key(),
             recipient: self.recipient.key(),
             lamports_sent,
             tokens_sent,
-        });
-
-        Ok(())
+        })
     }
 }
 
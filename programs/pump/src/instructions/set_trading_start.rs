@@ -0,0 +1,67 @@
+use crate::{
+    errors::PumpError,
+    states::{BondingCurve, Config},
+    utils::ensure_not_paused,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+#[event]
+pub struct TradingStartChanged {
+    pub mint: Pubkey,
+    pub creator: Pubkey,
+    pub trading_starts_at: i64,
+    pub timestamp: i64,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetTradingStart<'info> {
+    creator: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED_PREFIX.as_bytes()],
+        bump,
+    )]
+    global_config: Box<Account<'info, Config>>,
+
+    token_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [BondingCurve::SEED_PREFIX.as_bytes(), &token_mint.key().to_bytes()],
+        bump,
+        has_one = creator @ PumpError::NotAuthorized
+    )]
+    bonding_curve: Box<Account<'info, BondingCurve>>,
+}
+
+impl<'info> SetTradingStart<'info> {
+    //  returns the event for the handler to emit through a self-CPI
+    pub fn process(&mut self, trading_starts_at: i64) -> Result<TradingStartChanged> {
+        // global guards
+        ensure_not_paused(&self.global_config)?;
+
+        //  a start time in the past opens trading right away
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            now < self.bonding_curve.trading_starts_at,
+            PumpError::TradingAlreadyStarted
+        );
+        //  a presale still needs a window before public trading
+        if self.bonding_curve.presale_ends_at != 0 {
+            require!(
+                trading_starts_at < self.bonding_curve.presale_ends_at,
+                PumpError::IncorrectValue
+            );
+        }
+        self.bonding_curve.trading_starts_at = trading_starts_at;
+
+        Ok(TradingStartChanged {
+            mint: self.token_mint.key(),
+            creator: self.creator.key(),
+            trading_starts_at,
+            timestamp: now,
+        })
+    }
+}
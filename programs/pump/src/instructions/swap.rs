@@ -1,12 +1,111 @@
 use crate::{
-    errors::PumpError, states::{BondingCurve, Config}, utils::{ensure_not_completed, ensure_not_paused}
+    errors::PumpError, states::{BondingCurve, CappedPosition, Config, CreatorVault, FeeSplit, PositionKind, PresaleProof, ReferralStats, SwapDirection, SwapMode}, utils::{ensure_before_deadline, ensure_not_completed, ensure_not_paused, ensure_presale_allowed, ensure_trading_started}
 };
 use anchor_lang::{prelude::*, system_program};
+use pump_quote::Quote;
 use anchor_spl::{
     associated_token::{self, AssociatedToken},
     token::{self, Mint, Token, TokenAccount},
 };
 
+#[event]
+pub struct TradeEvent {
+    pub mint: Pubkey,
+    pub user: Pubkey,
+    pub direction: SwapDirection,
+    pub amount_in: u64,  //  lamports on buy, tokens on sell
+    pub amount_out: u64, //  tokens on buy, lamports on sell
+    pub fee: u64,        //  lamports, all shares included
+    pub fee_bps: u16,    //  rate of the fee, above the buy fee while the launch tax decays
+    pub launch_tax: u64, //  part of the fee paid to the tax treasury
+    pub creator_fee: u64,
+    pub referrer: Option<Pubkey>,
+    pub referral_fee: u64,
+    pub virtual_sol_reserves: u64,
+    pub virtual_token_reserves: u64,
+    pub real_sol_reserves: u64,
+    pub real_token_reserves: u64,
+    pub slot: u64,
+    pub timestamp: i64,
+}
+
+impl TradeEvent {
+    pub fn new(
+        mint: Pubkey,
+        user: Pubkey,
+        direction: SwapDirection,
+        quote: &Quote,
+        fees: &FeeSplit,
+        curve: &BondingCurve,
+        clock: &Clock,
+    ) -> Self {
+        Self {
+            mint,
+            user,
+            direction,
+            amount_in: quote.amount_in,
+            amount_out: quote.amount_out,
+            fee: quote.fee,
+            fee_bps: fees.fee_bps,
+            launch_tax: fees.tax,
+            creator_fee: fees.creator,
+            referrer: fees.referrer,
+            referral_fee: fees.referral,
+            virtual_sol_reserves: curve.virtual_sol_reserves,
+            virtual_token_reserves: curve.virtual_token_reserves,
+            real_sol_reserves: curve.real_sol_reserves,
+            real_token_reserves: curve.real_token_reserves,
+            slot: clock.slot,
+            timestamp: clock.unix_timestamp,
+        }
+    }
+
+    //  filled amounts handed back to the caller
+    pub fn result(&self) -> SwapResult {
+        SwapResult {
+            amount_in: self.amount_in,
+            amount_out: self.amount_out,
+            fee: self.fee,
+        }
+    }
+}
+
+//  filled amounts of a swap, returned through return data
+//  amount_in is less than requested when a buy only partially fills
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SwapResult {
+    pub amount_in: u64,  //  lamports on buy, tokens on sell
+    pub amount_out: u64, //  tokens on buy, lamports on sell
+    pub fee: u64,        //  lamports
+}
+
+#[event]
+pub struct CurveCompletedEvent {
+    pub mint: Pubkey,
+    pub user: Pubkey,
+    pub virtual_sol_reserves: u64,
+    pub virtual_token_reserves: u64,
+    pub real_sol_reserves: u64,
+    pub real_token_reserves: u64,
+    pub timestamp: i64,
+}
+
+impl CurveCompletedEvent {
+    //  only when the trade completed the curve
+    pub fn new(mint: Pubkey, user: Pubkey, curve: &BondingCurve, clock: &Clock) -> Option<Self> {
+        curve.is_completed.then_some(Self {
+            mint,
+            user,
+            virtual_sol_reserves: curve.virtual_sol_reserves,
+            virtual_token_reserves: curve.virtual_token_reserves,
+            real_sol_reserves: curve.real_sol_reserves,
+            real_token_reserves: curve.real_token_reserves,
+            timestamp: clock.unix_timestamp,
+        })
+    }
+}
+
+#[event_cpi]
 #[derive(Accounts)]
 pub struct Swap<'info> {
     #[account(mut)]
@@ -22,12 +121,24 @@ pub struct Swap<'info> {
         constraint = global_config.fee_recipient == fee_recipient.key() @PumpError::IncorrectFeeRecipient
     )]
     fee_recipient: AccountInfo<'info>,
+    /// CHECK: should be same with the tax treasury in the global_config
+    #[account(
+        mut,
+        constraint = global_config.tax_treasury == tax_treasury.key() @PumpError::IncorrectTaxTreasury
+    )]
+    tax_treasury: AccountInfo<'info>,
     #[account(
         mut,
         seeds = [BondingCurve::SEED_PREFIX.as_bytes(), &token_mint.key().to_bytes()],
         bump
     )]
     bonding_curve: Box<Account<'info, BondingCurve>>,
+    #[account(
+        mut,
+        seeds = [CreatorVault::SEED_PREFIX.as_bytes(), bonding_curve.creator.as_ref()],
+        bump
+    )]
+    creator_vault: Box<Account<'info, CreatorVault>>,
     
     token_mint: Box<Account<'info, Mint>>,
     #[account(
@@ -53,64 +164,201 @@ pub struct Swap<'info> {
 }
 
 impl<'info> Swap<'info> {
+    //  returns the events for the handler to emit through a self-CPI
     pub fn process(
         &mut self,
 
+        mode: SwapMode,
         amount: u64,
-        direction: u8,
-        min_out: u64,
+        direction: SwapDirection,
+        limit: u64,
+        deadline: Option<i64>, //  unix timestamp after which the swap is rejected
+        presale: Option<PresaleProof>, //  allowlist entry, needed to buy during a presale
+        early_buy: bool,               //  an early buy position is passed, needed to buy in the early window
+
+        //  with a presale proof: [presale position PDA, ..]
+        //  with early_buy: [.., early buy position PDA, ..]
+        //  optional referral: [.., referrer wallet, referral stats PDA of the referrer]
+        remaining_accounts: &[AccountInfo<'info>],
 
         bump_bonding_curve: u8,
-    ) -> Result<()> {
+    ) -> Result<(TradeEvent, Option<CurveCompletedEvent>)> {
         // global guards
-        ensure_not_paused(&self.global_config.as_ref())?;
-        ensure_not_completed(&self.global_config.as_ref())?;
+        ensure_not_paused(self.global_config.as_ref())?;
+        ensure_not_completed(self.global_config.as_ref())?;
+        let clock = Clock::get()?;
+        ensure_before_deadline(deadline, clock.unix_timestamp)?;
         let bonding_curve = &mut self.bonding_curve;
+        ensure_trading_started(bonding_curve.trading_starts_at, clock.unix_timestamp)?;
+        bonding_curve.mark_opened(clock.unix_timestamp, clock.slot);
 
         //  check curve is not completed
         require!(
-            bonding_curve.is_completed == false,
+            !bonding_curve.is_completed,
             PumpError::CurveAlreadyCompleted
         );
 
+        //  a presale proof brings the buyer's position PDA as the first remaining account
+        let (presale, remaining_accounts) = match presale {
+            Some(presale) => {
+                let (position, rest) = remaining_accounts
+                    .split_first()
+                    .ok_or(PumpError::InvalidPresaleAccount)?;
+                (Some((presale, position)), rest)
+            }
+            None => (None, remaining_accounts),
+        };
+        //  only allowlisted wallets buy until the presale ends, within their cap
+        let presale = match direction {
+            SwapDirection::Buy if bonding_curve.is_presale(clock.unix_timestamp) => {
+                let (presale, position) = presale.ok_or(PumpError::NotOnPresaleAllowlist)?;
+                ensure_presale_allowed(&bonding_curve.presale_merkle_root, &self.user.key(), &presale)?;
+                Some((presale.cap, position))
+            }
+            _ => None,
+        };
+
+        //  the early buy flag brings the buyer's position PDA next
+        let (early_buy, remaining_accounts) = match early_buy {
+            true => {
+                let (position, rest) = remaining_accounts
+                    .split_first()
+                    .ok_or(PumpError::InvalidEarlyBuyAccount)?;
+                (Some(position), rest)
+            }
+            false => (None, remaining_accounts),
+        };
+        //  wallets buy up to the configured cap in the early window after public trading opens
+        let early_buy = match direction {
+            SwapDirection::Buy
+                if bonding_curve.is_early(clock.slot, self.global_config.early_window_slots) =>
+            {
+                Some(early_buy.ok_or(PumpError::InvalidEarlyBuyAccount)?)
+            }
+            _ => None,
+        };
+
+        let referral = match remaining_accounts {
+            [] => None,
+            [referrer, stats, ..] => {
+                require!(
+                    referrer.is_writable && stats.is_writable,
+                    PumpError::InvalidReferrer
+                );
+                require_keys_neq!(referrer.key(), self.user.key(), PumpError::InvalidReferrer);
+                Some((referrer, stats))
+            }
+            _ => return err!(PumpError::InvalidReferrer),
+        };
+        let referrer = referral.map(|(referrer, _)| referrer);
+
         let curve_pda = &mut bonding_curve.to_account_info();
-        let global_config: &Box<Account<'info, Config>> = &self.global_config;
-
-        if direction == 0 {
-            //  buy - swap sol for token
-            bonding_curve.buy(
-                &self.token_mint,
-                global_config.curve_limit,
-                &self.user,
-                curve_pda,
-                &mut self.fee_recipient,
-                &mut self.user_token_account.to_account_info(),
-                &mut self.curve_token_account.to_account_info(),
-                amount,
-                min_out,
-                global_config.buy_fee_percent,
-                bump_bonding_curve,
+        let global_config: &Account<'info, Config> = &self.global_config;
+
+        let (quote, fees) = match direction {
+            SwapDirection::Buy => {
+                //  buy - swap sol for token, the launch tax decays once public trading opens
+                let fee_bps =
+                    global_config.buy_fee_bps_at(bonding_curve.slots_since_open(clock.slot))?;
+                bonding_curve.buy(
+                    &self.token_mint,
+                    global_config.curve_limit,
+                    &self.user,
+                    curve_pda,
+                    &mut self.fee_recipient,
+                    &mut self.creator_vault.to_account_info(),
+                    referrer,
+                    Some(&self.tax_treasury),
+                    &mut self.user_token_account.to_account_info(),
+                    &mut self.curve_token_account.to_account_info(),
+                    mode,
+                    amount,
+                    limit,
+                    fee_bps,
+                    global_config.buy_fee_bps,
+                    global_config.creator_fee_bps,
+                    global_config.referral_fee_bps,
+                    bump_bonding_curve,
+                    &self.system_program.to_account_info(),
+                    &self.token_program.to_account_info()
+                )?
+            }
+            SwapDirection::Sell => {
+                //  sell - swap token for sol
+                bonding_curve.sell(
+                    &self.token_mint,
+                    &self.user,
+                    curve_pda,
+                    &mut self.fee_recipient,
+                    &mut self.creator_vault.to_account_info(),
+                    referrer,
+                    &mut self.user_token_account.to_account_info(),
+                    &mut self.curve_token_account.to_account_info(),
+                    mode,
+                    amount,
+                    limit,
+                    global_config.sell_fee_bps,
+                    global_config.creator_fee_bps,
+                    global_config.referral_fee_bps,
+                    &self.token_program.to_account_info()
+                )?
+            }
+        };
+
+        if let Some((cap, position)) = presale {
+            CappedPosition::track(
+                PositionKind::Presale,
+                position,
+                &self.token_mint.key(),
+                &self.user.to_account_info(),
                 &self.system_program.to_account_info(),
-                &self.token_program.to_account_info()
+                quote.amount_out,
+                cap,
             )?;
-        } else if direction == 1 {
-            //  sell - swap token for sol
-            bonding_curve.sell(
-                &self.token_mint,
-                &self.user,
-                curve_pda,
-                &mut self.fee_recipient,
-                &mut self.user_token_account.to_account_info(),
-                &mut self.curve_token_account.to_account_info(),
-                amount,
-                min_out,
-                global_config.sell_fee_percent,
-                bump_bonding_curve,
+        }
+
+        if let Some(position) = early_buy {
+            CappedPosition::track(
+                PositionKind::EarlyBuy,
+                position,
+                &self.token_mint.key(),
+                &self.user.to_account_info(),
                 &self.system_program.to_account_info(),
-                &self.token_program.to_account_info()
+                quote.amount_out,
+                self.global_config.early_wallet_cap,
             )?;
         }
 
-        Ok(())
+        if let Some((referrer, stats)) = referral {
+            //  volume on the sol side, fee included
+            let volume = match direction {
+                SwapDirection::Buy => quote.amount_in,
+                SwapDirection::Sell => quote.amount_out.saturating_add(quote.fee),
+            };
+            ReferralStats::track(
+                stats,
+                referrer.key,
+                &self.user.to_account_info(),
+                &self.system_program.to_account_info(),
+                volume,
+                fees.referral,
+            )?;
+        }
+
+        let trade = TradeEvent::new(
+            self.token_mint.key(),
+            self.user.key(),
+            direction,
+            &quote,
+            &fees,
+            bonding_curve,
+            &clock,
+        );
+
+        //  this buy completed the curve
+        let completed =
+            CurveCompletedEvent::new(self.token_mint.key(), self.user.key(), bonding_curve, &clock);
+
+        Ok((trade, completed))
     }
 }
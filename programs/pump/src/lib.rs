@@ -1,3 +1,6 @@
+//  instruction handlers and curve trades take their accounts and limits one by one
+#![allow(clippy::too_many_arguments)]
+
 use anchor_lang::prelude::*;
 
 pub mod consts;
@@ -6,6 +9,9 @@ pub mod instructions;
 pub mod states;
 pub mod utils;
 
+#[cfg(feature = "cpi")]
+pub mod client;
+
 use crate::instructions::*;
 
 declare_id!("CaCK9zpnvkdwmzbTX45k99kBFAb9zbAm1EU8YoVWTFcB");
@@ -21,8 +27,15 @@ pub mod pump {
         ctx.accounts.process(new_config)
     }
 
+    //  called by admin once to move the global config from f64 fee
+    //  percentages to the basis point layout
+    pub fn migrate_config(ctx: Context<MigrateConfig>) -> Result<()> {
+        ctx.accounts.process()
+    }
+
     //  called by a creator to launch a token on the platform
     //  global guards: paused/completed enforced
+    //  charges Config.launch_fee_lamports unless the creator has a launch fee exemption
     pub fn launch<'info>(
         ctx: Context<'_, '_, '_, 'info, Launch<'info>>,
 
@@ -30,21 +43,159 @@ pub mod pump {
         name: String,
         symbol: String,
         uri: String,
+
+        //  pricing family, must be allowed by the config
+        curve_kind: states::CurveKind,
+
+        //  optional unix timestamp trading opens at, defaults to the launch time
+        trading_starts_at: Option<i64>,
+        //  optional allowlisted presale from the trading start until presale.ends_at
+        presale: Option<states::PresaleParams>,
+
+        //  optional creator dev buy in the same instruction, with its slippage and expiry
+        //  held to Config.early_wallet_cap when the config has an early window
+        initial_buy_lamports: Option<u64>,
+        min_tokens_out: u64,
+        deadline: Option<i64>,
+    ) -> Result<()> {
+        let (launched, dev_buy) = ctx.accounts.process(
+            name,
+            symbol,
+            uri,
+            curve_kind,
+            trading_starts_at,
+            presale,
+            initial_buy_lamports,
+            min_tokens_out,
+            deadline,
+            ctx.bumps.global_config,
+            ctx.bumps.bonding_curve,
+        )?;
+
+        emit_cpi!(launched);
+        if let Some((trade, completed)) = dev_buy {
+            emit_cpi!(trade);
+            if let Some(completed) = completed {
+                emit_cpi!(completed);
+            }
+        }
+
+        Ok(())
+    }
+
+    //  called by admin to waive the launch fee for a creator
+    pub fn add_launch_fee_exemption(
+        ctx: Context<AddLaunchFeeExemption>,
+        creator: Pubkey,
     ) -> Result<()> {
-        ctx.accounts
-            .process(name, symbol, uri, ctx.bumps.global_config)
+        ctx.accounts.process(creator)
+    }
+
+    //  called by admin to charge the launch fee to a creator again
+    pub fn remove_launch_fee_exemption(
+        ctx: Context<RemoveLaunchFeeExemption>,
+        _creator: Pubkey,
+    ) -> Result<()> {
+        ctx.accounts.process()
+    }
+
+    //  called by the creator to move the trading start of a curve, only before it has passed
+    //  global guards: paused enforced
+    pub fn set_trading_start(ctx: Context<SetTradingStart>, trading_starts_at: i64) -> Result<()> {
+        let changed = ctx.accounts.process(trading_starts_at)?;
+        emit_cpi!(changed);
+
+        Ok(())
     }
 
     //  called by a user to swap token/sol
+    //  direction: 0 buy, 1 sell (SwapDirection), anything else fails with InvalidSwapDirection
     //  global guards: paused/completed enforced
+    //  trading start: swaps before the curve's trading_starts_at are rejected
+    //  deadline: optional unix timestamp, the swap fails once it has passed
+    //  returns the filled (amount in used, amount out, fee) via return data for CPI callers
+    //  presale: buys before the curve's presale ends need an allowlist proof, with the
+    //  presale position PDA as the first remaining account
+    //  early_buy: the early buy position PDA follows, needed by buys in the early window
+    //  referral: optional remaining accounts [referrer wallet, referral stats PDA]
     pub fn swap<'info>(
         ctx: Context<'_, '_, '_, 'info, Swap<'info>>,
         amount: u64,
         direction: u8,
         min_out: u64,
-    ) -> Result<()> {
-        ctx.accounts
-            .process(amount, direction, min_out, ctx.bumps.bonding_curve)
+        deadline: Option<i64>,
+        presale: Option<states::PresaleProof>,
+        early_buy: bool,
+    ) -> Result<SwapResult> {
+        let (trade, completed) = ctx.accounts.process(
+            states::SwapMode::ExactIn,
+            amount,
+            states::SwapDirection::try_from(direction)?,
+            min_out,
+            deadline,
+            presale,
+            early_buy,
+            ctx.remaining_accounts,
+            ctx.bumps.bonding_curve,
+        )?;
+
+        let result = trade.result();
+        emit_cpi!(trade);
+        if let Some(completed) = completed {
+            emit_cpi!(completed);
+        }
+
+        Ok(result)
+    }
+
+    //  called by a user to buy an exact token amount or sell for an exact sol amount
+    //  direction: 0 buy, 1 sell, as in swap
+    //  global guards: paused/completed enforced
+    pub fn swap_exact_out<'info>(
+        ctx: Context<'_, '_, '_, 'info, Swap<'info>>,
+        amount_out: u64,
+        direction: u8,
+        max_in: u64,
+        deadline: Option<i64>,
+        presale: Option<states::PresaleProof>,
+        early_buy: bool,
+    ) -> Result<SwapResult> {
+        let (trade, completed) = ctx.accounts.process(
+            states::SwapMode::ExactOut,
+            amount_out,
+            states::SwapDirection::try_from(direction)?,
+            max_in,
+            deadline,
+            presale,
+            early_buy,
+            ctx.remaining_accounts,
+            ctx.bumps.bonding_curve,
+        )?;
+
+        let result = trade.result();
+        emit_cpi!(trade);
+        if let Some(completed) = completed {
+            emit_cpi!(completed);
+        }
+
+        Ok(result)
+    }
+
+    //  read-only preview of an exact in swap against the live curve
+    //  returns amount in, amount out, fee and the price after the trade via return data
+    //  global guards: paused/completed enforced
+    //  direction: 0 buy, 1 sell, as in swap
+    pub fn quote(ctx: Context<Quote>, amount: u64, direction: u8) -> Result<SwapQuote> {
+        ctx.accounts.process(amount, states::SwapDirection::try_from(direction)?)
+    }
+
+    //  called by a creator to withdraw their share of trade fees
+    //  global guards: paused enforced
+    pub fn claim_creator_fees(ctx: Context<ClaimCreatorFees>) -> Result<()> {
+        let claimed = ctx.accounts.process()?;
+        emit_cpi!(claimed);
+
+        Ok(())
     }
 
     ////////////////////    DM if you want full implementation  ////////////////////
@@ -65,6 +216,9 @@ pub mod pump {
     pub fn release_reserves<'info>(
         ctx: Context<'_, '_, '_, 'info, ReleaseReserves<'info>>,
     ) -> Result<()> {
-        ctx.accounts.process(ctx.bumps.bonding_curve)
+        let released = ctx.accounts.process(ctx.bumps.bonding_curve)?;
+        emit_cpi!(released);
+
+        Ok(())
     }
 }
@@ -1,8 +1,146 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::Mint;
 
+use pump_quote::{CurveState, Quote};
+
+use crate::consts::INITIAL_PRICE;
 use crate::errors::PumpError;
-use crate::utils::{sol_transfer_from_user, sol_transfer_with_signer, token_transfer_user, token_transfer_with_signer};
+use crate::utils::{
+    sol_transfer_from_pda, sol_transfer_from_user, token_transfer_user, token_transfer_with_signer,
+};
+
+//  direction of a swap on the curve
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SwapDirection {
+    Buy,  //  sol for token
+    Sell, //  token for sol
+}
+
+//  instructions take the direction as a u8 so an unknown value fails with our own error
+impl TryFrom<u8> for SwapDirection {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self> {
+        match value {
+            0 => Ok(Self::Buy),
+            1 => Ok(Self::Sell),
+            _ => err!(PumpError::InvalidSwapDirection),
+        }
+    }
+}
+
+impl From<SwapDirection> for u8 {
+    fn from(direction: SwapDirection) -> Self {
+        match direction {
+            SwapDirection::Buy => 0,
+            SwapDirection::Sell => 1,
+        }
+    }
+}
+
+//  which side of a swap is fixed by the caller
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SwapMode {
+    ExactIn,  //  amount is the input, limit is the minimum output
+    ExactOut, //  amount is the output, limit is the maximum input
+}
+
+//  pricing family of a curve, picked by the creator at launch
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CurveKind {
+    ConstantProduct, //  x * y = k on the virtual reserves
+    Linear,          //  price grows linearly with tokens sold
+    Exponential,     //  price grows by a fixed ratio per step of tokens sold
+}
+
+impl CurveKind {
+    //  bit of this kind in `Config.allowed_curve_kinds`
+    pub fn mask(self) -> u8 {
+        1 << self as u8
+    }
+}
+
+impl From<CurveKind> for pump_quote::CurveKind {
+    fn from(kind: CurveKind) -> Self {
+        match kind {
+            CurveKind::ConstantProduct => Self::ConstantProduct,
+            CurveKind::Linear => Self::Linear,
+            CurveKind::Exponential => Self::Exponential,
+        }
+    }
+}
+
+//  lamports of a trade fee by recipient
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FeeSplit {
+    pub platform: u64, //  team wallet
+    pub creator: u64,  //  creator vault
+    pub referral: u64, //  referrer, out of the platform part
+    pub tax: u64,      //  tax treasury, launch tax above the base buy fee
+
+    pub referrer: Option<Pubkey>,
+    pub fee_bps: u16, //  rate the whole fee was charged at
+}
+
+impl FeeSplit {
+    //  creator share comes off the whole fee, referral share off what is left for the platform
+    pub fn new(
+        fee_lamports: u64,
+        fee_bps: u16,
+        creator_fee_bps: u16,
+        referral: Option<(Pubkey, u16)>, //  referrer and its share in basis points
+    ) -> Result<Self> {
+        let creator = pump_quote::math::fee_share(fee_lamports, creator_fee_bps)
+            .map_err(PumpError::from)?;
+        let platform = fee_lamports - creator;
+        let referral_fee = match referral {
+            Some((_, bps)) => pump_quote::math::fee_share(platform, bps).map_err(PumpError::from)?,
+            None => 0,
+        };
+
+        Ok(Self {
+            platform: platform - referral_fee,
+            creator,
+            referral: referral_fee,
+            tax: 0,
+            referrer: referral.map(|(referrer, _)| referrer),
+            fee_bps,
+        })
+    }
+
+    //  launch tax is the part of the fee above the base rate, the rest splits as in new
+    pub fn with_tax(
+        fee_lamports: u64,
+        fee_bps: u16,
+        base_fee_bps: u16,
+        creator_fee_bps: u16,
+        referral: Option<(Pubkey, u16)>,
+    ) -> Result<Self> {
+        let tax = pump_quote::math::tax_share(fee_lamports, base_fee_bps, fee_bps)
+            .map_err(PumpError::from)?;
+
+        Ok(Self {
+            tax,
+            ..Self::new(fee_lamports - tax, fee_bps, creator_fee_bps, referral)?
+        })
+    }
+
+    //  a payout leaving the referrer below rent exemption would fail the whole trade
+    //  the platform keeps such a share instead
+    pub fn payable_to_referrer(self, referrer_lamports: u64, rent_exempt_lamports: u64) -> Self {
+        if self.referral == 0
+            || referrer_lamports.saturating_add(self.referral) >= rent_exempt_lamports
+        {
+            return self;
+        }
+
+        Self {
+            platform: self.platform + self.referral,
+            referral: 0,
+            ..self
+        }
+    }
+}
 
 #[account]
 pub struct BondingCurve {
@@ -19,11 +157,30 @@ pub struct BondingCurve {
 
     //  true - if the curve reached the limit
     pub is_completed: bool,
+
+    //  launcher of the curve, gets a share of every trade fee
+    pub creator: Pubkey,
+
+    //  pricing family and its parameters, fixed at launch
+    pub curve_kind: CurveKind,
+    pub curve_growth_bps: u32,
+    pub initial_real_token_reserves: u64,
+
+    //  unix timestamp trading opens at, the creator can move it until then
+    pub trading_starts_at: i64,
+
+    //  only allowlisted wallets buy until this unix timestamp, zero without a presale
+    pub presale_ends_at: i64,
+    pub presale_merkle_root: [u8; 32],
+
+    //  slot public trading opened at, zero until then
+    //  starts the early trading window
+    pub opened_slot: u64,
 }
 
 impl<'info> BondingCurve {
     pub const SEED_PREFIX: &'static str = "bonding-curve";
-    pub const LEN: usize = 8 * 5 + 1;
+    pub const LEN: usize = 8 * 5 + 1 + 32 + 1 + 4 + 8 + 8 + 8 + 32 + 8;
 
     //  get signer for bonding curve PDA
     pub fn get_signer<'a>(mint: &'a Pubkey, bump: &'a u8) -> [&'a [u8]; 3] {
@@ -34,6 +191,37 @@ impl<'info> BondingCurve {
         ]
     }
 
+    //  buys need an allowlist proof until the presale ends
+    pub fn is_presale(&self, now: i64) -> bool {
+        now < self.presale_ends_at
+    }
+
+    //  public trading opens at the trading start, or when the presale ends
+    pub fn is_open(&self, now: i64) -> bool {
+        now >= self.trading_starts_at && !self.is_presale(now)
+    }
+
+    //  record the slot public trading opened at, on launch or the first swap after it
+    pub fn mark_opened(&mut self, now: i64, slot: u64) {
+        if self.opened_slot == 0 && self.is_open(now) {
+            self.opened_slot = slot;
+        }
+    }
+
+    //  slots since public trading opened, zero until it has
+    pub fn slots_since_open(&self, slot: u64) -> u64 {
+        match self.opened_slot {
+            0 => 0,
+            opened_slot => slot.saturating_sub(opened_slot),
+        }
+    }
+
+    //  buys are capped per wallet for `window_slots` slots after public trading opens
+    //  presale buys only have their allowlist cap
+    pub fn is_early(&self, slot: u64, window_slots: u64) -> bool {
+        self.opened_slot != 0 && self.slots_since_open(slot) < window_slots
+    }
+
     //  update reserve balance on the curve PDA
     pub fn update_reserves(&mut self, reserve_lamport: u64, reserve_token: u64) -> Result<bool> {
         self.virtual_sol_reserves = reserve_lamport;
@@ -42,6 +230,82 @@ impl<'info> BondingCurve {
         Ok(false)
     }
 
+    //  update real balance held by the curve PDA
+    pub fn update_real_reserves(&mut self, reserve_lamport: u64, reserve_token: u64) -> Result<()> {
+        self.real_sol_reserves = reserve_lamport;
+        self.real_token_reserves = reserve_token;
+
+        Ok(())
+    }
+
+    //  book a filled buy, sol in is net of the fee
+    //  completes the curve once it sold out or reached the limit
+    pub fn apply_buy(&mut self, sol_in: u64, token_out: u64, curve_limit: u64) -> Result<()> {
+        //  calculate new reserves
+        let new_token_reserves = self
+            .virtual_token_reserves
+            .checked_sub(token_out)
+            .ok_or(PumpError::OverflowOrUnderflowOccurred)?;
+
+        let new_sol_reserves = self
+            .virtual_sol_reserves
+            .checked_add(sol_in)
+            .ok_or(PumpError::OverflowOrUnderflowOccurred)?;
+
+        let new_real_token_reserves = self
+            .real_token_reserves
+            .checked_sub(token_out)
+            .ok_or(PumpError::NotEnoughTokenReserves)?;
+
+        let new_real_sol_reserves = self
+            .real_sol_reserves
+            .checked_add(sol_in)
+            .ok_or(PumpError::OverflowOrUnderflowOccurred)?;
+
+        msg! {"Reserves:: Token: {:?} SOL: {:?}", new_token_reserves, new_sol_reserves};
+
+        //  update reserves on the curve
+        self.update_reserves(new_sol_reserves, new_token_reserves)?;
+        self.update_real_reserves(new_real_sol_reserves, new_real_token_reserves)?;
+
+        //  complete the curve if it sold out or reached the limit
+        if self.real_token_reserves == 0 || self.real_sol_reserves >= curve_limit {
+            self.is_completed = true;
+        }
+
+        Ok(())
+    }
+
+    //  book a filled sell, sol out is the payout with the fee
+    pub fn apply_sell(&mut self, token_in: u64, sol_out: u64) -> Result<()> {
+        //  calculate new reserves
+        let new_token_reserves = self
+            .virtual_token_reserves
+            .checked_add(token_in)
+            .ok_or(PumpError::OverflowOrUnderflowOccurred)?;
+
+        let new_sol_reserves = self
+            .virtual_sol_reserves
+            .checked_sub(sol_out)
+            .ok_or(PumpError::OverflowOrUnderflowOccurred)?;
+
+        let new_real_token_reserves = self
+            .real_token_reserves
+            .checked_add(token_in)
+            .ok_or(PumpError::OverflowOrUnderflowOccurred)?;
+
+        let new_real_sol_reserves = self
+            .real_sol_reserves
+            .checked_sub(sol_out)
+            .ok_or(PumpError::OverflowOrUnderflowOccurred)?;
+
+        msg! {"Reserves:: Token: {:?} SOL: {:?}", new_token_reserves, new_sol_reserves};
+
+        //  update reserves on the curve
+        self.update_reserves(new_sol_reserves, new_token_reserves)?;
+        self.update_real_reserves(new_real_sol_reserves, new_real_token_reserves)
+    }
+
     //  swap sol for token
     pub fn buy(
         &mut self,
@@ -49,35 +313,70 @@ impl<'info> BondingCurve {
         curve_limit: u64,                  //  bonding curve limit
         user: &Signer<'info>,              //  user address
 
-        curve_pda: &mut AccountInfo<'info>,     //  bonding curve PDA
+        curve_pda: &mut AccountInfo<'info>, //  bonding curve PDA
         fee_recipient: &mut AccountInfo<'info>, //  team wallet address to get fee
+        creator_vault: &mut AccountInfo<'info>, //  creator vault PDA to get the creator share
+        referrer: Option<&AccountInfo<'info>>,  //  referrer wallet to get the referral share
+        tax_treasury: Option<&AccountInfo<'info>>, //  treasury to get the launch tax
 
         user_ata: &mut AccountInfo<'info>, //  associated toke accounts for user
         curve_ata: &mut AccountInfo<'info>, //  associated toke accounts for curve
 
-        amount_in: u64,      //  sol amount to pay
-        min_amount_out: u64, //  minimum amount out
-        fee_percent: f64,    //  buy fee
+        mode: SwapMode,        //  exact sol in or exact token out
+        amount: u64,           //  sol to pay (exact in) or token to receive (exact out)
+        limit: u64,            //  minimum token out (exact in) or maximum sol in (exact out)
+        fee_bps: u16,          //  buy fee in basis points, launch tax included
+        base_fee_bps: u16,     //  buy fee without the launch tax
+        creator_fee_bps: u16,  //  creator share of the fee in basis points
+        referral_fee_bps: u16, //  referrer share of the platform fee in basis points
 
         curve_bump: u8, // bump for signer
 
         system_program: &AccountInfo<'info>, //  system program
         token_program: &AccountInfo<'info>,  //  token program
-    ) -> Result<bool> {
-        
-        let (amount_out, fee_lamports) =
-            self.calc_amount_out(amount_in, token_mint.decimals, 0, fee_percent)?;
-
-        //  check min amount out
-        require!(
-            amount_out >= min_amount_out,
-            PumpError::ReturnAmountTooSmall
-        );
+    ) -> Result<(Quote, FeeSplit)> {
+        let Quote {
+            amount_in,
+            amount_out,
+            fee: fee_lamports,
+        } = self.fill_buy(
+            mode,
+            amount,
+            limit,
+            token_mint.decimals,
+            fee_bps,
+            curve_limit,
+        )?;
 
-        //  transfer fee to team wallet
-        sol_transfer_from_user(&user, fee_recipient, system_program, fee_lamports)?;
+        //  transfer fee to team wallet, creator vault, referrer and tax treasury
+        let referral = referrer.map(|referrer| (referrer.key(), referral_fee_bps));
+        let fees = match tax_treasury {
+            Some(_) => FeeSplit::with_tax(
+                fee_lamports,
+                fee_bps,
+                base_fee_bps,
+                creator_fee_bps,
+                referral,
+            )?,
+            None => FeeSplit::new(fee_lamports, fee_bps, creator_fee_bps, referral)?,
+        };
+        let fees = match referrer {
+            Some(referrer) => fees.payable_to_referrer(
+                referrer.lamports(),
+                Rent::get()?.minimum_balance(referrer.data_len()),
+            ),
+            None => fees,
+        };
+        sol_transfer_from_user(user, fee_recipient, system_program, fees.platform)?;
+        sol_transfer_from_user(user, creator_vault, system_program, fees.creator)?;
+        if let Some(referrer) = referrer {
+            sol_transfer_from_user(user, referrer, system_program, fees.referral)?;
+        }
+        if let Some(tax_treasury) = tax_treasury {
+            sol_transfer_from_user(user, tax_treasury, system_program, fees.tax)?;
+        }
         //  transfer adjusted amount to curve
-        sol_transfer_from_user(&user, curve_pda, system_program, amount_in - fee_lamports)?;
+        sol_transfer_from_user(user, curve_pda, system_program, amount_in - fee_lamports)?;
         //  transfer token from PDA to user
         token_transfer_with_signer(
             curve_ata,
@@ -88,30 +387,16 @@ impl<'info> BondingCurve {
             amount_out,
         )?;
 
-        //  calculate new reserves
-        let new_token_reserves = self
-            .virtual_token_reserves
-            .checked_sub(amount_out)
-            .ok_or(PumpError::OverflowOrUnderflowOccurred)?;
-
-        let new_sol_reserves = self
-            .virtual_sol_reserves
-            .checked_add(amount_in - fee_lamports)
-            .ok_or(PumpError::OverflowOrUnderflowOccurred)?;
-
-        msg! {"Reserves:: Token: {:?} SOL: {:?}", new_token_reserves, new_sol_reserves};
-
         //  update reserves on the curve
-        self.update_reserves(new_sol_reserves, new_token_reserves)?;
+        self.apply_buy(amount_in - fee_lamports, amount_out, curve_limit)?;
 
-        //  return true if the curve reached the limit
-        if new_sol_reserves >= curve_limit {
-            self.is_completed = true;
-            return Ok(true);
-        }
+        let trade = Quote {
+            amount_in,
+            amount_out,
+            fee: fee_lamports,
+        };
 
-        //  return false, curve is not reached the limit
-        Ok(false)
+        Ok((trade, fees))
     }
 
     //  swap token for sol
@@ -122,86 +407,245 @@ impl<'info> BondingCurve {
 
         curve_pda: &mut AccountInfo<'info>, //  bonding curve PDA
         fee_recipient: &mut AccountInfo<'info>, //  team wallet address to get fee
+        creator_vault: &mut AccountInfo<'info>, //  creator vault PDA to get the creator share
+        referrer: Option<&AccountInfo<'info>>,  //  referrer wallet to get the referral share
 
         user_ata: &mut AccountInfo<'info>, //  associated toke accounts for user
         curve_ata: &mut AccountInfo<'info>, //  associated toke accounts for curve
 
-        amount_in: u64,      //  sol amount to pay
-        min_amount_out: u64, //  minimum amount out
-        fee_percent: f64,    //  sell fee
-
-        curve_bump: u8, // bump for signer
-        
-        system_program: &AccountInfo<'info>, //  system program
-        token_program: &AccountInfo<'info>,  //  token program
-    ) -> Result<()> {
-        let (amount_out, fee_lamports) =
-            self.calc_amount_out(amount_in, token_mint.decimals, 1, fee_percent)?;
+        mode: SwapMode,        //  exact token in or exact sol out
+        amount: u64,           //  token to sell (exact in) or sol to receive (exact out)
+        limit: u64,            //  minimum sol out (exact in) or maximum token in (exact out)
+        fee_bps: u16,          //  sell fee in basis points
+        creator_fee_bps: u16,  //  creator share of the fee in basis points
+        referral_fee_bps: u16, //  referrer share of the platform fee in basis points
+
+        token_program: &AccountInfo<'info>, //  token program
+    ) -> Result<(Quote, FeeSplit)> {
+        let (amount_in, amount_out, fee_lamports) = self.quote(
+            mode,
+            amount,
+            limit,
+            token_mint.decimals,
+            SwapDirection::Sell,
+            fee_bps,
+        )?;
 
-        //  check min amount out
+        //  the curve PDA holds account data, so the system program can't debit it
+        //  pay out of its lamports directly and never dip below rent exemption
+        let payout = amount_out
+            .checked_add(fee_lamports)
+            .ok_or(PumpError::OverflowOrUnderflowOccurred)?;
+        let min_balance = Rent::get()?.minimum_balance(curve_pda.data_len());
         require!(
-            amount_out >= min_amount_out,
-            PumpError::ReturnAmountTooSmall
+            payout <= curve_pda.lamports().saturating_sub(min_balance),
+            PumpError::NotEnoughSolReserves
         );
 
-        let token = token_mint.key();
-        let signer_seeds: &[&[&[u8]]] = &[&BondingCurve::get_signer(&token, &curve_bump)];
-        //  transfer fee to team wallet
-        sol_transfer_with_signer(
-            &user,
-            fee_recipient,
-            system_program,
-            signer_seeds,
+        //  transfer token from user to PDA, before touching lamports directly
+        token_transfer_user(user_ata, user, curve_ata, token_program, amount_in)?;
+        //  transfer fee to team wallet, creator vault and referrer
+        let fees = FeeSplit::new(
             fee_lamports,
+            fee_bps,
+            creator_fee_bps,
+            referrer.map(|referrer| (referrer.key(), referral_fee_bps)),
         )?;
-        //  transfer SOL to curve PDA
-        sol_transfer_with_signer(
-            &user,
-            curve_pda,
-            &system_program,
-            signer_seeds,
-            amount_in - fee_lamports,
-        )?;
-        //  transfer token from user to PDA
-        token_transfer_user(
-            user_ata,
-            user,
-            curve_ata,
-            token_program,
+        let fees = match referrer {
+            Some(referrer) => fees.payable_to_referrer(
+                referrer.lamports(),
+                Rent::get()?.minimum_balance(referrer.data_len()),
+            ),
+            None => fees,
+        };
+        sol_transfer_from_pda(curve_pda, fee_recipient, fees.platform)?;
+        sol_transfer_from_pda(curve_pda, creator_vault, fees.creator)?;
+        if let Some(referrer) = referrer {
+            sol_transfer_from_pda(curve_pda, referrer, fees.referral)?;
+        }
+        //  transfer SOL to user
+        sol_transfer_from_pda(curve_pda, &user.to_account_info(), amount_out)?;
+
+        //  update reserves on the curve
+        self.apply_sell(amount_in, payout)?;
+
+        let trade = Quote {
+            amount_in,
             amount_out,
-        )?;
+            fee: fee_lamports,
+        };
 
-        //  calculate new reserves
-        let new_token_reserves = self
-            .virtual_token_reserves
-            .checked_add(amount_in)
-            .ok_or(PumpError::OverflowOrUnderflowOccurred)?;
+        Ok((trade, fees))
+    }
 
-        let new_sol_reserves = self
-            .virtual_sol_reserves
-            .checked_sub(amount_out + fee_lamports)
-            .ok_or(PumpError::OverflowOrUnderflowOccurred)?;
+    //  the curve as seen by the shared quote math
+    pub fn curve_state(&self) -> CurveState {
+        CurveState {
+            kind: self.curve_kind.into(),
+            virtual_sol_reserves: self.virtual_sol_reserves,
+            virtual_token_reserves: self.virtual_token_reserves,
+            real_sol_reserves: self.real_sol_reserves,
+            real_token_reserves: self.real_token_reserves,
+            initial_real_token_reserves: self.initial_real_token_reserves,
+            start_price: INITIAL_PRICE,
+            growth_bps: self.curve_growth_bps,
+        }
+    }
 
-        msg! {"Reserves:: Token: {:?} SOL: {:?}", new_token_reserves, new_sol_reserves};
+    //  most tokens a buy can take before the curve completes
+    pub fn max_buy_amount_out(&self, curve_limit: u64) -> Result<u64> {
+        let max_amount_out = pump_quote::max_buy_amount_out(&self.curve_state(), curve_limit)
+            .map_err(PumpError::from)?;
 
-        //  update reserves on the curve
-        self.update_reserves(new_sol_reserves, new_token_reserves)?;
+        Ok(max_amount_out)
+    }
 
-        Ok(())
+    //  price an exact in swap without moving funds
+    //  returns the filled trade and the curve state after it
+    pub fn preview(
+        &self,
+        direction: SwapDirection,
+        amount: u64,
+        token_decimal: u8,
+        fee_bps: u16,
+        curve_limit: u64,
+    ) -> Result<(Quote, CurveState)> {
+        let state = self.curve_state();
+        let (trade, after) = match direction {
+            SwapDirection::Buy => {
+                let trade = self.fill_buy(
+                    SwapMode::ExactIn,
+                    amount,
+                    0,
+                    token_decimal,
+                    fee_bps,
+                    curve_limit,
+                )?;
+                (trade, state.after_buy(&trade))
+            }
+            SwapDirection::Sell => {
+                let (amount_in, amount_out, fee) =
+                    self.quote(SwapMode::ExactIn, amount, 0, token_decimal, direction, fee_bps)?;
+                let trade = Quote {
+                    amount_in,
+                    amount_out,
+                    fee,
+                };
+                (trade, state.after_sell(&trade))
+            }
+        };
+
+        Ok((trade, after.map_err(PumpError::from)?))
+    }
+
+    //  resolve a buy request, the buy that crosses the limit only fills up to it
+    //  the user is charged for the filled part and keeps the rest
+    pub fn fill_buy(
+        &self,
+        mode: SwapMode,
+        amount: u64,
+        limit: u64,
+        token_decimal: u8,
+        fee_bps: u16,
+        curve_limit: u64,
+    ) -> Result<Quote> {
+        let (mut amount_in, mut amount_out, mut fee_lamports) =
+            self.quote(mode, amount, limit, token_decimal, SwapDirection::Buy, fee_bps)?;
+
+        let max_amount_out = self.max_buy_amount_out(curve_limit)?;
+        if amount_out > max_amount_out {
+            //  an exact amount out can't be partially filled
+            require!(mode == SwapMode::ExactIn, PumpError::NotEnoughTokenReserves);
+
+            (amount_in, fee_lamports) =
+                self.calc_amount_in(max_amount_out, token_decimal, SwapDirection::Buy, fee_bps)?;
+            amount_out = max_amount_out;
+
+            //  the caller's minimum out holds for the filled part too
+            require!(amount_out >= limit, PumpError::ReturnAmountTooSmall);
+
+            msg!(
+                "Partial fill:: Token: {:?} SOL: {:?}",
+                amount_out,
+                amount_in
+            );
+        }
+
+        Ok(Quote {
+            amount_in,
+            amount_out,
+            fee: fee_lamports,
+        })
+    }
+
+    //  resolve a swap request to (amount in, amount out, fee lamports)
+    //  and check it against the caller's slippage limit
+    fn quote(
+        &self,
+        mode: SwapMode,
+        amount: u64,
+        limit: u64,
+        token_decimal: u8,
+        direction: SwapDirection,
+        fee_bps: u16,
+    ) -> Result<(u64, u64, u64)> {
+        match mode {
+            SwapMode::ExactIn => {
+                let (amount_out, fee_lamports) =
+                    self.calc_amount_out(amount, token_decimal, direction, fee_bps)?;
+
+                //  check min amount out
+                require!(amount_out >= limit, PumpError::ReturnAmountTooSmall);
+
+                Ok((amount, amount_out, fee_lamports))
+            }
+            SwapMode::ExactOut => {
+                let (amount_in, fee_lamports) =
+                    self.calc_amount_in(amount, token_decimal, direction, fee_bps)?;
+
+                //  check max amount in
+                require!(amount_in <= limit, PumpError::InputAmountTooLarge);
+
+                Ok((amount_in, amount, fee_lamports))
+            }
+        }
     }
 
     //  calculate amount out and fee lamports
+    //  fee is always charged on the SOL side and every rounding favors the curve
     fn calc_amount_out(
-        &mut self,
-        _amount_in: u64,
+        &self,
+        amount_in: u64,
+        _token_decimal: u8, //  decimal for token
+        direction: SwapDirection,
+        fee_bps: u16,
+    ) -> Result<(u64, u64)> {
+        let state = self.curve_state();
+        let quote = match direction {
+            SwapDirection::Buy => pump_quote::quote_buy(&state, amount_in, fee_bps),
+            SwapDirection::Sell => pump_quote::quote_sell(&state, amount_in, fee_bps),
+        }
+        .map_err(PumpError::from)?;
+
+        Ok((quote.amount_out, quote.fee))
+    }
+
+    //  calculate amount in and fee lamports for a fixed amount out
+    //  inverse of calc_amount_out, every rounding favors the curve
+    fn calc_amount_in(
+        &self,
+        amount_out: u64,
         _token_decimal: u8, //  decimal for token
-        _direction: u8,     //  0 - buy, 1 - sell
-        _fee_percent: f64,
+        direction: SwapDirection,
+        fee_bps: u16,
     ) -> Result<(u64, u64)> {
-        //  implement your curve fomula
-        let amount_out = 0;
-        let fee_lamports = 0;
+        let state = self.curve_state();
+        let quote = match direction {
+            SwapDirection::Buy => pump_quote::quote_buy_exact_out(&state, amount_out, fee_bps),
+            SwapDirection::Sell => pump_quote::quote_sell_exact_out(&state, amount_out, fee_bps),
+        }
+        .map_err(PumpError::from)?;
 
-        Ok((amount_out, fee_lamports))
+        Ok((quote.amount_in, quote.fee))
     }
 }
@@ -0,0 +1,96 @@
+use anchor_lang::{prelude::*, system_program};
+
+use crate::{errors::PumpError, utils::create_pda_account};
+
+//  per-wallet buy cap a position tracks, each kind has its own PDA
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PositionKind {
+    Presale,  //  allowlist cap until the presale ends
+    EarlyBuy, //  config cap during the early trading window
+}
+
+impl PositionKind {
+    pub fn seed_prefix(self) -> &'static str {
+        match self {
+            Self::Presale => "presale-position",
+            Self::EarlyBuy => "early-buy-position",
+        }
+    }
+
+    fn invalid_account(self) -> PumpError {
+        match self {
+            Self::Presale => PumpError::InvalidPresaleAccount,
+            Self::EarlyBuy => PumpError::InvalidEarlyBuyAccount,
+        }
+    }
+
+    fn cap_exceeded(self) -> PumpError {
+        match self {
+            Self::Presale => PumpError::PresaleCapExceeded,
+            Self::EarlyBuy => PumpError::EarlyBuyCapExceeded,
+        }
+    }
+}
+
+//  tokens a wallet bought on a curve while a per-wallet cap applied
+#[account]
+pub struct CappedPosition {
+    pub mint: Pubkey,
+    pub user: Pubkey,
+    pub bought: u64,
+}
+
+impl CappedPosition {
+    pub const LEN: usize = 32 + 32 + 8;
+
+    pub fn address(kind: PositionKind, mint: &Pubkey, user: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[kind.seed_prefix().as_bytes(), mint.as_ref(), user.as_ref()],
+            &crate::ID,
+        )
+    }
+
+    //  add a capped buy to the wallet's position PDA, creating it on first use
+    //  fails once the wallet's total goes over the cap
+    pub fn track<'info>(
+        kind: PositionKind,
+        position: &AccountInfo<'info>,       //  position PDA of this kind
+        mint: &Pubkey,                       //  mint of the curve
+        user: &AccountInfo<'info>,           //  buyer, pays the rent
+        system_program: &AccountInfo<'info>, //  system program
+        bought: u64,
+        cap: u64,
+    ) -> Result<()> {
+        let (address, bump) = Self::address(kind, mint, user.key);
+        require_keys_eq!(position.key(), address, kind.invalid_account());
+        require!(position.is_writable, kind.invalid_account());
+
+        let mut capped_position = if position.owner == &system_program::ID {
+            let signer_seeds: &[&[&[u8]]] = &[&[
+                kind.seed_prefix().as_bytes(),
+                mint.as_ref(),
+                user.key.as_ref(),
+                &[bump],
+            ]];
+            create_pda_account(position, user, system_program, signer_seeds, 8 + Self::LEN)?;
+
+            CappedPosition {
+                mint: *mint,
+                user: *user.key,
+                bought: 0,
+            }
+        } else {
+            require_keys_eq!(*position.owner, crate::ID, kind.invalid_account());
+            Self::try_deserialize(&mut &position.try_borrow_data()?[..])?
+        };
+
+        capped_position.bought = capped_position
+            .bought
+            .checked_add(bought)
+            .ok_or(PumpError::OverflowOrUnderflowOccurred)?;
+        require!(capped_position.bought <= cap, kind.cap_exceeded());
+
+        let mut data = position.try_borrow_mut_data()?;
+        capped_position.try_serialize(&mut &mut data[..])
+    }
+}
@@ -1,4 +1,10 @@
-use anchor_lang::prelude::*;
+use anchor_lang::{prelude::*, Discriminator};
+
+use crate::{
+    consts::{EXPONENTIAL_STEPS, MAX_FEE_BPS, MAX_NAME_LENGTH, MAX_SYMBOL_LENGTH, MAX_URI_LENGTH},
+    errors::PumpError,
+    states::CurveKind,
+};
 
 #[account]
 pub struct Config {
@@ -14,17 +20,196 @@ pub struct Config {
     pub initial_real_token_reserves: u64,
     pub total_token_supply: u64,
 
-    //  platform fee percentage
-    pub buy_fee_percent: f64,
-    pub sell_fee_percent: f64,
-    pub migration_fee_percent: f64,
+    //  platform fee in basis points (1 bps = 0.01%)
+    pub buy_fee_bps: u16,
+    pub sell_fee_bps: u16,
+    pub migration_fee_bps: u16,
+    //  share of each trade fee paid to the curve creator, in basis points of the fee
+    pub creator_fee_bps: u16,
+    //  share of the platform part of a fee paid to the referrer of a swap, in basis points
+    pub referral_fee_bps: u16,
+    //  flat fee in lamports charged to the creator at launch, sent to the fee recipient
+    pub launch_fee_lamports: u64,
 
     //  safety rails
     pub paused: bool,
     pub is_completed: bool,
+
+    //  curve families creators may pick at launch, one bit per `CurveKind`
+    pub allowed_curve_kinds: u8,
+    //  price growth over the whole supply of a linear curve
+    pub linear_growth_bps: u32,
+    //  price growth per step of an exponential curve
+    pub exponential_growth_bps: u32,
+
+    //  longest token metadata accepted at launch, in bytes
+    pub max_name_len: u8,
+    pub max_symbol_len: u8,
+    pub max_uri_len: u16,
+
+    //  anti-sniper window after public trading opens, in slots, zero turns it off
+    pub early_window_slots: u64,
+    //  tokens each wallet may buy on a curve during the window
+    pub early_wallet_cap: u64,
+
+    //  buy fee until public trading opens, decays linearly to `buy_fee_bps` over `launch_tax_decay_slots`
+    pub launch_tax_bps: u16,
+    pub launch_tax_decay_slots: u64,
+    //  wallet receiving the launch tax above the normal buy fee
+    pub tax_treasury: Pubkey,
 }
 
 impl Config {
     pub const SEED_PREFIX: &'static str = "global-config";
+    pub const LEN: usize = 32 + 32 + 8 + 8 * 4 + 2 * 5 + 8 + 1 + 1 + 1 + 4 + 4 + 1 + 1 + 2 + 8 + 8 + 2 + 8 + 32;
+
+    pub fn is_curve_kind_allowed(&self, kind: CurveKind) -> bool {
+        self.allowed_curve_kinds & kind.mask() != 0
+    }
+
+    //  growth parameter a new curve of this kind starts with
+    pub fn curve_growth_bps(&self, kind: CurveKind) -> u32 {
+        match kind {
+            CurveKind::ConstantProduct => 0,
+            CurveKind::Linear => self.linear_growth_bps,
+            CurveKind::Exponential => self.exponential_growth_bps,
+        }
+    }
+
+    //  buy fee with the launch tax decayed for the slots since public trading opened
+    pub fn buy_fee_bps_at(&self, slots_since_open: u64) -> Result<u16> {
+        let fee_bps = pump_quote::math::decayed_fee_bps(
+            self.buy_fee_bps,
+            self.launch_tax_bps,
+            self.launch_tax_decay_slots,
+            slots_since_open,
+        )
+        .map_err(PumpError::from)?;
+
+        Ok(fee_bps)
+    }
+
+    //  check the fee and reserve values are in range
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.buy_fee_bps <= MAX_FEE_BPS
+                && self.sell_fee_bps <= MAX_FEE_BPS
+                && self.migration_fee_bps <= MAX_FEE_BPS
+                && self.creator_fee_bps <= MAX_FEE_BPS
+                && self.referral_fee_bps <= MAX_FEE_BPS
+                && self.launch_tax_bps <= MAX_FEE_BPS,
+            PumpError::IncorrectValue
+        );
+        //  the curve sells its real reserves, priced off virtual reserves that include them
+        require!(
+            self.initial_real_token_reserves > 0
+                && self.initial_real_token_reserves <= self.initial_virtual_token_reserves
+                && self.initial_real_token_reserves <= self.total_token_supply,
+            PumpError::IncorrectValue
+        );
+
+        //  metadata limits can't exceed what the metadata program accepts
+        require!(
+            (1..=MAX_NAME_LENGTH).contains(&(self.max_name_len as usize))
+                && (1..=MAX_SYMBOL_LENGTH).contains(&(self.max_symbol_len as usize))
+                && (1..=MAX_URI_LENGTH).contains(&(self.max_uri_len as usize)),
+            PumpError::IncorrectValue
+        );
+
+        //  an early window with no cap would block every buy in it
+        if self.early_window_slots > 0 {
+            require!(self.early_wallet_cap > 0, PumpError::IncorrectValue);
+        }
+
+        //  exponential curves step over the tokens for sale
+        if self.is_curve_kind_allowed(CurveKind::Exponential) {
+            require!(
+                self.initial_real_token_reserves >= EXPONENTIAL_STEPS,
+                PumpError::IncorrectValue
+            );
+        }
+
+        Ok(())
+    }
+}
+
+//  layout of the global config before fees moved to basis points
+//  only used to migrate the existing `global-config` PDA
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct LegacyConfig {
+    pub authority: Pubkey,
+    pub fee_recipient: Pubkey,
+
+    pub curve_limit: u64,
+
+    pub initial_virtual_token_reserves: u64,
+    pub initial_virtual_sol_reserves: u64,
+    pub initial_real_token_reserves: u64,
+    pub total_token_supply: u64,
+
+    pub buy_fee_percent: f64,
+    pub sell_fee_percent: f64,
+    pub migration_fee_percent: f64,
+
+    pub paused: bool,
+    pub is_completed: bool,
+}
+
+impl LegacyConfig {
     pub const LEN: usize = 32 + 32 + 8 + 8 * 4 + 8 * 3 + 1 + 1;
+
+    //  read the legacy account data, discriminator included, as a checked new config
+    pub fn migrate(data: &[u8]) -> Result<Config> {
+        require!(data.len() == 8 + Self::LEN, PumpError::IncorrectValue);
+        require!(
+            data[..8] == Config::DISCRIMINATOR,
+            PumpError::IncorrectValue
+        );
+
+        let config = Self::deserialize(&mut &data[8..])?.into_config()?;
+        config.validate()?;
+
+        Ok(config)
+    }
+
+    //  convert the legacy layout, fee percentages are rounded to the nearest bps
+    pub fn into_config(self) -> Result<Config> {
+        Ok(Config {
+            authority: self.authority,
+            fee_recipient: self.fee_recipient,
+            curve_limit: self.curve_limit,
+            initial_virtual_token_reserves: self.initial_virtual_token_reserves,
+            initial_virtual_sol_reserves: self.initial_virtual_sol_reserves,
+            initial_real_token_reserves: self.initial_real_token_reserves,
+            total_token_supply: self.total_token_supply,
+            buy_fee_bps: percent_to_bps(self.buy_fee_percent)?,
+            sell_fee_bps: percent_to_bps(self.sell_fee_percent)?,
+            migration_fee_bps: percent_to_bps(self.migration_fee_percent)?,
+            creator_fee_bps: 0,
+            referral_fee_bps: 0,
+            launch_fee_lamports: 0,
+            paused: self.paused,
+            is_completed: self.is_completed,
+            allowed_curve_kinds: CurveKind::ConstantProduct.mask(),
+            linear_growth_bps: 0,
+            exponential_growth_bps: 0,
+            max_name_len: MAX_NAME_LENGTH as u8,
+            max_symbol_len: MAX_SYMBOL_LENGTH as u8,
+            max_uri_len: MAX_URI_LENGTH as u16,
+            early_window_slots: 0,
+            early_wallet_cap: 0,
+            launch_tax_bps: 0,
+            launch_tax_decay_slots: 0,
+            tax_treasury: self.fee_recipient,
+        })
+    }
+}
+
+fn percent_to_bps(percent: f64) -> Result<u16> {
+    require!(
+        (0.0..=100.0).contains(&percent),
+        PumpError::IncorrectValue
+    );
+
+    Ok((percent * 100.0).round() as u16)
 }
@@ -0,0 +1,13 @@
+use anchor_lang::prelude::*;
+
+//  holds the creator's share of trade fees until claimed
+//  one per creator, shared by all of their curves
+#[account]
+pub struct CreatorVault {
+    pub creator: Pubkey,
+}
+
+impl CreatorVault {
+    pub const SEED_PREFIX: &'static str = "creator-vault";
+    pub const LEN: usize = 32;
+}
@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+//  marks a creator as exempt from `Config.launch_fee_lamports`
+//  one per creator, created and closed by the admin
+#[account]
+pub struct LaunchFeeExemption {
+    pub creator: Pubkey,
+}
+
+impl LaunchFeeExemption {
+    pub const SEED_PREFIX: &'static str = "launch-fee-exemption";
+    pub const LEN: usize = 32;
+
+    //  the exemption exists once the PDA is owned by this program
+    pub fn is_exempt(exemption: &AccountInfo) -> bool {
+        exemption.owner == &crate::ID && !exemption.data_is_empty()
+    }
+}
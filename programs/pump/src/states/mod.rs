@@ -1,4 +1,14 @@
 pub mod config;
 pub use config::*;
 pub mod bonding_curve;
-pub use bonding_curve::*;
\ No newline at end of file
+pub use bonding_curve::*;
+pub mod creator_vault;
+pub use creator_vault::*;
+pub mod referral_stats;
+pub use referral_stats::*;
+pub mod launch_fee_exemption;
+pub use launch_fee_exemption::*;
+pub mod presale;
+pub use presale::*;
+pub mod capped_position;
+pub use capped_position::*;
\ No newline at end of file
@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+//  presale set by the creator at launch, it opens at the curve's trading start
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PresaleParams {
+    pub ends_at: i64,          //  unix timestamp public trading opens at
+    pub merkle_root: [u8; 32], //  root of the (wallet, cap) allowlist
+}
+
+//  allowlist entry of a presale buyer
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PresaleProof {
+    pub cap: u64,               //  tokens the wallet may buy during the presale
+    pub proof: Vec<[u8; 32]>, //  sibling hashes from the leaf up to the root
+}
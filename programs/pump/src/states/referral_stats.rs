@@ -0,0 +1,57 @@
+use anchor_lang::{prelude::*, system_program};
+
+use crate::{errors::PumpError, utils::create_pda_account};
+
+//  running totals of the trades routed through a referrer
+#[account]
+pub struct ReferralStats {
+    pub referrer: Pubkey,
+    pub volume: u64, //  lamports traded, fee included
+    pub fees: u64,   //  lamports paid to the referrer
+    pub trades: u64,
+}
+
+impl ReferralStats {
+    pub const SEED_PREFIX: &'static str = "referral-stats";
+    pub const LEN: usize = 32 + 8 * 3;
+
+    //  add a trade to the referrer's stats PDA, creating it on first use
+    //  the trader pays the rent of a new stats account
+    pub fn track<'info>(
+        stats: &AccountInfo<'info>,          //  referral stats PDA
+        referrer: &Pubkey,                   //  referrer wallet
+        payer: &AccountInfo<'info>,          //  trader
+        system_program: &AccountInfo<'info>, //  system program
+        volume: u64,
+        fee: u64,
+    ) -> Result<()> {
+        let (address, bump) = Pubkey::find_program_address(
+            &[Self::SEED_PREFIX.as_bytes(), referrer.as_ref()],
+            &crate::ID,
+        );
+        require_keys_eq!(stats.key(), address, PumpError::InvalidReferrer);
+
+        let mut referral_stats = if stats.owner == &system_program::ID {
+            let signer_seeds: &[&[&[u8]]] =
+                &[&[Self::SEED_PREFIX.as_bytes(), referrer.as_ref(), &[bump]]];
+            create_pda_account(stats, payer, system_program, signer_seeds, 8 + Self::LEN)?;
+
+            ReferralStats {
+                referrer: *referrer,
+                volume: 0,
+                fees: 0,
+                trades: 0,
+            }
+        } else {
+            require_keys_eq!(*stats.owner, crate::ID, PumpError::InvalidReferrer);
+            Self::try_deserialize(&mut &stats.try_borrow_data()?[..])?
+        };
+
+        referral_stats.volume = referral_stats.volume.saturating_add(volume);
+        referral_stats.fees = referral_stats.fees.saturating_add(fee);
+        referral_stats.trades = referral_stats.trades.saturating_add(1);
+
+        let mut data = stats.try_borrow_mut_data()?;
+        referral_stats.try_serialize(&mut &mut data[..])
+    }
+}
@@ -1,6 +1,10 @@
 use anchor_lang::prelude::*;
 
-use crate::{errors::PumpError, states::Config};
+use crate::{
+    errors::PumpError,
+    states::{Config, PresaleProof},
+    utils::{presale_leaf, verify_merkle_proof},
+};
 
 pub fn ensure_not_paused(config: &Config) -> Result<()> {
     require!(!config.paused, PumpError::ProgramPaused);
@@ -15,4 +19,49 @@ pub fn ensure_not_completed(config: &Config) -> Result<()> {
 pub fn ensure_admin(config: &Config, admin_key: &Pubkey) -> Result<()> {
     require_keys_eq!(config.authority, *admin_key, PumpError::NotAuthorized);
     Ok(())
-}
\ No newline at end of file
+}
+
+pub fn ensure_before_deadline(deadline: Option<i64>, now: i64) -> Result<()> {
+    if let Some(deadline) = deadline {
+        require!(now <= deadline, PumpError::DeadlineExceeded);
+    }
+    Ok(())
+}
+
+pub fn ensure_trading_started(trading_starts_at: i64, now: i64) -> Result<()> {
+    require!(now >= trading_starts_at, PumpError::TradingNotStarted);
+    Ok(())
+}
+
+//  the wallet and its cap must be a leaf of the presale allowlist
+pub fn ensure_presale_allowed(root: &[u8; 32], user: &Pubkey, presale: &PresaleProof) -> Result<()> {
+    require!(
+        verify_merkle_proof(&presale.proof, root, presale_leaf(user, presale.cap)),
+        PumpError::NotOnPresaleAllowlist
+    );
+    Ok(())
+}
+
+//  name may be any text without control characters, symbol is ASCII alphanumeric
+//  and uri is printable ASCII without spaces, all within the config limits
+pub fn ensure_valid_metadata(config: &Config, name: &str, symbol: &str, uri: &str) -> Result<()> {
+    require!(
+        !name.is_empty()
+            && name.len() <= config.max_name_len as usize
+            && !name.chars().any(char::is_control),
+        PumpError::InvalidTokenName
+    );
+    require!(
+        !symbol.is_empty()
+            && symbol.len() <= config.max_symbol_len as usize
+            && symbol.bytes().all(|b| b.is_ascii_alphanumeric()),
+        PumpError::InvalidTokenSymbol
+    );
+    require!(
+        !uri.is_empty()
+            && uri.len() <= config.max_uri_len as usize
+            && uri.bytes().all(|b| b.is_ascii_graphic()),
+        PumpError::InvalidTokenUri
+    );
+    Ok(())
+}
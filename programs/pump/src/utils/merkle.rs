@@ -0,0 +1,19 @@
+use anchor_lang::{prelude::*, solana_program::hash::hashv};
+
+//  leaf of a presale allowlist entry: the wallet and its cap in tokens
+pub fn presale_leaf(user: &Pubkey, cap: u64) -> [u8; 32] {
+    hashv(&[user.as_ref(), &cap.to_le_bytes()]).to_bytes()
+}
+
+//  hash the leaf up the proof, each pair is hashed in sorted order
+pub fn verify_merkle_proof(proof: &[[u8; 32]], root: &[u8; 32], leaf: [u8; 32]) -> bool {
+    let computed = proof.iter().fold(leaf, |node, sibling| {
+        if node <= *sibling {
+            hashv(&[&node, sibling]).to_bytes()
+        } else {
+            hashv(&[sibling, &node]).to_bytes()
+        }
+    });
+
+    computed == *root
+}
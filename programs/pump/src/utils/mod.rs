@@ -1,6 +1,8 @@
-pub mod calc;
-pub use calc::*;
 pub mod transfer;
 pub use transfer::*;
 pub mod guards;
 pub use guards::*;
+pub mod pda;
+pub use pda::*;
+pub mod merkle;
+pub use merkle::*;
\ No newline at end of file
@@ -0,0 +1,62 @@
+use anchor_lang::{prelude::*, system_program};
+
+//  allocate a program-owned PDA, topping up lamports someone may have sent to the address already
+pub fn create_pda_account<'info>(
+    account: &AccountInfo<'info>,        //  PDA to create
+    payer: &AccountInfo<'info>,          //  pays the rent
+    system_program: &AccountInfo<'info>, //  system program
+    signer_seeds: &[&[&[u8]]],           //  seeds of the PDA, bump included
+    space: usize,
+) -> Result<()> {
+    let rent_lamports = Rent::get()?.minimum_balance(space);
+
+    if account.lamports() == 0 {
+        return system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                system_program::CreateAccount {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+                signer_seeds,
+            ),
+            rent_lamports,
+            space as u64,
+            &crate::ID,
+        );
+    }
+
+    let top_up = rent_lamports.saturating_sub(account.lamports());
+    if top_up > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            top_up,
+        )?;
+    }
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            system_program::Allocate {
+                account_to_allocate: account.clone(),
+            },
+            signer_seeds,
+        ),
+        space as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            system_program::Assign {
+                account_to_assign: account.clone(),
+            },
+            signer_seeds,
+        ),
+        &crate::ID,
+    )
+}
@@ -7,6 +7,8 @@ use anchor_lang::{
 };
 use anchor_spl::token;
 
+use crate::errors::PumpError;
+
 //  transfer sol from user
 pub fn sol_transfer_from_user<'info>(
     signer: &Signer<'info>,
@@ -49,6 +51,27 @@ pub fn sol_transfer_with_signer<'info>(
     Ok(())
 }
 
+//  transfer sol out of a program-owned PDA that holds data
+pub fn sol_transfer_from_pda<'info>(
+    source: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    let source_lamports = source
+        .lamports()
+        .checked_sub(amount)
+        .ok_or(PumpError::OverflowOrUnderflowOccurred)?;
+    let destination_lamports = destination
+        .lamports()
+        .checked_add(amount)
+        .ok_or(PumpError::OverflowOrUnderflowOccurred)?;
+
+    **source.try_borrow_mut_lamports()? = source_lamports;
+    **destination.try_borrow_mut_lamports()? = destination_lamports;
+
+    Ok(())
+}
+
 //  transfer token from user
 pub fn token_transfer_user<'info>(
     from: &AccountInfo<'info>,
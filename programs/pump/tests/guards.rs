@@ -1,22 +1,65 @@
+use anchor_lang::{AccountDeserialize, AccountSerialize, AnchorSerialize, Discriminator};
 use pump::errors::PumpError;
-use pump::states::Config;
-use pump::utils::{ensure_admin, ensure_not_completed, ensure_not_paused};
-use solana_program::pubkey::Pubkey;
+use pump::states::{
+    BondingCurve, CappedPosition, Config, CurveKind, FeeSplit, LegacyConfig, PositionKind,
+    PresaleProof, SwapDirection, SwapMode,
+};
+use pump::utils::{
+    ensure_admin, ensure_before_deadline, ensure_not_completed, ensure_not_paused,
+    ensure_presale_allowed, ensure_trading_started, ensure_valid_metadata, presale_leaf,
+};
+use solana_program::{
+    account_info::AccountInfo, hash::hashv, pubkey::Pubkey, rent::Rent, system_program,
+};
 
 fn dummy_config(paused: bool, completed: bool, authority: Pubkey) -> Config {
     Config {
         authority,
         fee_recipient: Pubkey::new_unique(),
-        curve_limit: 0,
-        initial_virtual_token_reserves: 0,
-        initial_virtual_sol_reserves: 0,
-        initial_real_token_reserves: 0,
-        total_token_supply: 0,
-        buy_fee_percent: 0.0,
-        sell_fee_percent: 0.0,
-        migration_fee_percent: 0.0,
+        curve_limit: 85_000_000_000,
+        initial_virtual_token_reserves: 1_073_000_000_000_000,
+        initial_virtual_sol_reserves: 30_000_000_000,
+        initial_real_token_reserves: 793_100_000_000_000,
+        total_token_supply: 1_000_000_000_000_000,
+        buy_fee_bps: 0,
+        sell_fee_bps: 0,
+        migration_fee_bps: 0,
+        creator_fee_bps: 0,
+        referral_fee_bps: 0,
+        launch_fee_lamports: 0,
         paused,
         is_completed: completed,
+        allowed_curve_kinds: 0,
+        linear_growth_bps: 0,
+        exponential_growth_bps: 0,
+        max_name_len: 32,
+        max_symbol_len: 10,
+        max_uri_len: 200,
+        early_window_slots: 0,
+        early_wallet_cap: 0,
+        launch_tax_bps: 0,
+        launch_tax_decay_slots: 0,
+        tax_treasury: Pubkey::new_unique(),
+    }
+}
+
+//  constant product curve with nothing sold yet
+fn dummy_curve() -> BondingCurve {
+    BondingCurve {
+        virtual_token_reserves: 1_073_000_000_000_000,
+        virtual_sol_reserves: 30_000_000_000,
+        real_token_reserves: 793_100_000_000_000,
+        real_sol_reserves: 0,
+        token_total_supply: 1_000_000_000_000_000,
+        is_completed: false,
+        creator: Pubkey::new_unique(),
+        curve_kind: CurveKind::ConstantProduct,
+        curve_growth_bps: 0,
+        initial_real_token_reserves: 793_100_000_000_000,
+        trading_starts_at: 0,
+        presale_ends_at: 0,
+        presale_merkle_root: [0; 32],
+        opened_slot: 0,
     }
 }
 
@@ -26,6 +69,21 @@ async fn errors_exist() {
     let _ = PumpError::ProgramCompleted;
 }
 
+#[tokio::test]
+async fn error_codes_are_stable() {
+    for (error, code) in [
+        (PumpError::NotAuthorized, 6000),
+        (PumpError::ReturnAmountTooSmall, 6003),
+        (PumpError::OverflowOrUnderflowOccurred, 6004),
+        (PumpError::CurveAlreadyCompleted, 6005),
+        (PumpError::ProgramPaused, 6006),
+        (PumpError::ProgramCompleted, 6007),
+        (PumpError::InputAmountTooLarge, 6008),
+    ] {
+        assert_eq!(u32::from(error), code);
+    }
+}
+
 #[tokio::test]
 async fn guard_not_paused_ok() {
     let cfg = dummy_config(false, false, Pubkey::new_unique());
@@ -65,4 +123,469 @@ async fn guard_admin_err() {
     let not_admin = Pubkey::new_unique();
     let err = ensure_admin(&cfg, &not_admin).unwrap_err();
     assert_eq!(err, PumpError::NotAuthorized.into());
-}
\ No newline at end of file
+}
+
+#[tokio::test]
+async fn guard_deadline_ok() {
+    assert!(ensure_before_deadline(None, 1_700_000_000).is_ok());
+    assert!(ensure_before_deadline(Some(1_700_000_000), 1_700_000_000).is_ok());
+}
+
+#[tokio::test]
+async fn guard_deadline_err() {
+    let err = ensure_before_deadline(Some(1_700_000_000), 1_700_000_001).unwrap_err();
+    assert_eq!(err, PumpError::DeadlineExceeded.into());
+}
+
+#[tokio::test]
+async fn guard_trading_started_ok() {
+    assert!(ensure_trading_started(1_700_000_000, 1_700_000_000).is_ok());
+    assert!(ensure_trading_started(0, 1_700_000_000).is_ok());
+}
+
+#[tokio::test]
+async fn guard_trading_started_err() {
+    let err = ensure_trading_started(1_700_000_001, 1_700_000_000).unwrap_err();
+    assert_eq!(err, PumpError::TradingNotStarted.into());
+}
+
+fn hash_pair(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
+    if a <= b {
+        hashv(&[&a, &b]).to_bytes()
+    } else {
+        hashv(&[&b, &a]).to_bytes()
+    }
+}
+
+#[tokio::test]
+async fn guard_presale_allowed() {
+    let (alice, bob, carol) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+    let (a, b, c) = (
+        presale_leaf(&alice, 1_000),
+        presale_leaf(&bob, 2_000),
+        presale_leaf(&carol, 3_000),
+    );
+    let ab = hash_pair(a, b);
+    let root = hash_pair(ab, c);
+
+    let proof = |cap, proof| PresaleProof { cap, proof };
+    assert!(ensure_presale_allowed(&root, &alice, &proof(1_000, vec![b, c])).is_ok());
+    assert!(ensure_presale_allowed(&root, &carol, &proof(3_000, vec![ab])).is_ok());
+
+    //  wrong cap, wrong wallet, wrong proof
+    for (user, entry) in [
+        (alice, proof(5_000, vec![b, c])),
+        (Pubkey::new_unique(), proof(1_000, vec![b, c])),
+        (bob, proof(2_000, vec![c])),
+    ] {
+        let err = ensure_presale_allowed(&root, &user, &entry).unwrap_err();
+        assert_eq!(err, PumpError::NotOnPresaleAllowlist.into());
+    }
+}
+
+#[tokio::test]
+async fn guard_metadata_ok() {
+    let cfg = dummy_config(false, false, Pubkey::new_unique());
+    assert!(ensure_valid_metadata(&cfg, "My Token 🚀", "MYT", "https://example.com/m.json").is_ok());
+}
+
+#[tokio::test]
+async fn guard_metadata_err() {
+    let cfg = dummy_config(false, false, Pubkey::new_unique());
+    let uri = "https://example.com/m.json";
+
+    let err = ensure_valid_metadata(&cfg, &"a".repeat(33), "MYT", uri).unwrap_err();
+    assert_eq!(err, PumpError::InvalidTokenName.into());
+    let err = ensure_valid_metadata(&cfg, "My\nToken", "MYT", uri).unwrap_err();
+    assert_eq!(err, PumpError::InvalidTokenName.into());
+    let err = ensure_valid_metadata(&cfg, "My Token", "MY T", uri).unwrap_err();
+    assert_eq!(err, PumpError::InvalidTokenSymbol.into());
+    let err = ensure_valid_metadata(&cfg, "My Token", "", uri).unwrap_err();
+    assert_eq!(err, PumpError::InvalidTokenSymbol.into());
+    let err = ensure_valid_metadata(&cfg, "My Token", "MYT", "https://example.com/m y.json").unwrap_err();
+    assert_eq!(err, PumpError::InvalidTokenUri.into());
+}
+
+#[tokio::test]
+async fn config_early_window_needs_cap() {
+    let mut cfg = dummy_config(false, false, Pubkey::new_unique());
+    cfg.early_window_slots = 150;
+    let err = cfg.validate().unwrap_err();
+    assert_eq!(err, PumpError::IncorrectValue.into());
+
+    cfg.early_wallet_cap = 10_000_000_000_000;
+    assert!(cfg.validate().is_ok());
+}
+
+#[tokio::test]
+async fn config_needs_real_reserves() {
+    let mut cfg = dummy_config(false, false, Pubkey::new_unique());
+    assert!(cfg.validate().is_ok());
+
+    //  nothing to sell
+    cfg.initial_real_token_reserves = 0;
+    assert_eq!(cfg.validate().err().unwrap(), PumpError::IncorrectValue.into());
+
+    //  more for sale than the curve prices in
+    cfg.initial_real_token_reserves = cfg.initial_virtual_token_reserves + 1;
+    assert_eq!(cfg.validate().err().unwrap(), PumpError::IncorrectValue.into());
+}
+
+#[tokio::test]
+async fn config_launch_tax_decays() {
+    let mut cfg = dummy_config(false, false, Pubkey::new_unique());
+    cfg.buy_fee_bps = 100;
+    cfg.launch_tax_bps = 5_000;
+    cfg.launch_tax_decay_slots = 100;
+
+    assert_eq!(cfg.buy_fee_bps_at(0).unwrap(), 5_000);
+    assert_eq!(cfg.buy_fee_bps_at(50).unwrap(), 2_550);
+    assert_eq!(cfg.buy_fee_bps_at(100).unwrap(), 100);
+    assert_eq!(cfg.buy_fee_bps_at(4_000).unwrap(), 100);
+}
+
+#[tokio::test]
+async fn config_launch_tax_waits_for_scheduled_start() {
+    let mut cfg = dummy_config(false, false, Pubkey::new_unique());
+    cfg.buy_fee_bps = 100;
+    cfg.launch_tax_bps = 5_000;
+    cfg.launch_tax_decay_slots = 100;
+
+    //  launched at slot 100 with trading scheduled an hour later
+    let mut curve = dummy_curve();
+    curve.trading_starts_at = 1_700_003_600;
+    curve.mark_opened(1_700_000_000, 100);
+
+    //  long after launch the tax is still whole, trading hasn't opened
+    let fee_at = |curve: &BondingCurve, slot| cfg.buy_fee_bps_at(curve.slots_since_open(slot)).unwrap();
+    assert_eq!(fee_at(&curve, 9_100), 5_000);
+
+    //  and decays from the first swap once it has
+    curve.mark_opened(1_700_003_600, 9_100);
+    assert_eq!(fee_at(&curve, 9_100), 5_000);
+    assert_eq!(fee_at(&curve, 9_150), 2_550);
+    assert_eq!(fee_at(&curve, 9_200), 100);
+}
+
+#[tokio::test]
+async fn swap_direction_out_of_range() {
+    assert_eq!(SwapDirection::try_from(0u8).unwrap(), SwapDirection::Buy);
+    assert_eq!(SwapDirection::try_from(1u8).unwrap(), SwapDirection::Sell);
+    assert_eq!(u8::from(SwapDirection::Sell), 1);
+
+    //  swap, swap_exact_out and quote map their u8 argument through this
+    let err = SwapDirection::try_from(2u8).unwrap_err();
+    assert_eq!(err, PumpError::InvalidSwapDirection.into());
+}
+
+#[tokio::test]
+async fn fee_split_sums_to_the_fee() {
+    const BPS: [u16; 4] = [0, 1, 9_999, 10_000];
+    let referrer = Pubkey::new_unique();
+
+    for fee_lamports in [0, 1, 3, 9_999, 1_000_000_007, u64::MAX] {
+        for creator_bps in BPS {
+            for referral in BPS.map(|bps| Some((referrer, bps))).into_iter().chain([None]) {
+                let fees = FeeSplit::new(fee_lamports, 100, creator_bps, referral).unwrap();
+                let total = fees.platform as u128 + fees.creator as u128 + fees.referral as u128;
+                assert_eq!(total, fee_lamports as u128);
+                assert_eq!(fees.tax, 0);
+
+                for (base_bps, fee_bps) in [(0, 1), (1, 10_000), (9_999, 10_000), (100, 100)] {
+                    let fees =
+                        FeeSplit::with_tax(fee_lamports, fee_bps, base_bps, creator_bps, referral)
+                            .unwrap();
+                    let total = fees.platform as u128
+                        + fees.creator as u128
+                        + fees.referral as u128
+                        + fees.tax as u128;
+                    assert_eq!(total, fee_lamports as u128);
+                }
+            }
+        }
+    }
+}
+
+#[tokio::test]
+async fn fee_split_referral_comes_out_of_the_platform_part() {
+    let referrer = Pubkey::new_unique();
+
+    let fees = FeeSplit::new(10_000, 100, 2_000, Some((referrer, 5_000))).unwrap();
+    assert_eq!((fees.platform, fees.creator, fees.referral), (4_000, 2_000, 4_000));
+    assert_eq!(fees.referrer, Some(referrer));
+
+    //  each share rounds down, the platform keeps the dust
+    let fees = FeeSplit::new(7, 100, 3_333, Some((referrer, 5_000))).unwrap();
+    assert_eq!((fees.platform, fees.creator, fees.referral), (3, 2, 2));
+
+    //  a full referral share takes the whole platform part, never the creator's
+    let fees = FeeSplit::new(10_001, 100, 2_000, Some((referrer, 10_000))).unwrap();
+    assert_eq!((fees.platform, fees.creator, fees.referral), (0, 2_000, 8_001));
+
+    let err = FeeSplit::new(10_000, 100, 2_000, Some((referrer, 10_001))).unwrap_err();
+    assert_eq!(err, PumpError::IncorrectValue.into());
+}
+
+#[tokio::test]
+async fn fee_split_skips_a_referral_below_rent() {
+    let rent_exempt = Rent::default().minimum_balance(0);
+    let fees = FeeSplit::new(10_000, 100, 2_000, Some((Pubkey::new_unique(), 5_000))).unwrap();
+
+    //  an unfunded referrer can't receive a few thousand lamports, the platform keeps them
+    let skipped = fees.payable_to_referrer(0, rent_exempt);
+    assert_eq!((skipped.platform, skipped.creator, skipped.referral), (8_000, 2_000, 0));
+
+    //  a funded one is paid
+    assert_eq!(fees.payable_to_referrer(rent_exempt, rent_exempt), fees);
+    assert_eq!(fees.payable_to_referrer(rent_exempt - 4_000, rent_exempt), fees);
+}
+
+#[tokio::test]
+async fn curve_max_buy_amount_out() {
+    let curve = dummy_curve();
+    let curve_limit = 1_000_000_000;
+
+    //  buying the max reaches the limit, a lamport short of the limit buys less
+    let max_amount_out = curve.max_buy_amount_out(curve_limit).unwrap();
+    let state = curve.curve_state();
+    assert!(pump_quote::quote_buy_exact_out(&state, max_amount_out, 0).unwrap().amount_in >= curve_limit);
+    assert!(pump_quote::quote_buy(&state, curve_limit - 1, 0).unwrap().amount_out < max_amount_out);
+
+    //  a limit no buy can reach leaves every real token for sale
+    assert_eq!(curve.max_buy_amount_out(u64::MAX).unwrap(), curve.real_token_reserves);
+}
+
+#[tokio::test]
+async fn curve_partial_fill_keeps_min_out() {
+    let curve = dummy_curve();
+    let curve_limit = 1_000_000_000;
+    let max_amount_out = curve.max_buy_amount_out(curve_limit).unwrap();
+    let amount = 5 * curve_limit;
+
+    let fill = curve
+        .fill_buy(SwapMode::ExactIn, amount, max_amount_out, 6, 100, curve_limit)
+        .unwrap();
+    assert_eq!(fill.amount_out, max_amount_out);
+    assert!(fill.amount_in < amount);
+
+    //  the full request clears this minimum, the partially filled part doesn't
+    let err = curve
+        .fill_buy(SwapMode::ExactIn, amount, max_amount_out + 1, 6, 100, curve_limit)
+        .unwrap_err();
+    assert_eq!(err, PumpError::ReturnAmountTooSmall.into());
+}
+
+#[tokio::test]
+async fn config_migrates_legacy_layout() {
+    let fee_recipient = Pubkey::new_unique();
+    let legacy = LegacyConfig {
+        authority: Pubkey::new_unique(),
+        fee_recipient,
+        curve_limit: 85_000_000_000,
+        initial_virtual_token_reserves: 1_073_000_000_000_000,
+        initial_virtual_sol_reserves: 30_000_000_000,
+        initial_real_token_reserves: 793_100_000_000_000,
+        total_token_supply: 1_000_000_000_000_000,
+        buy_fee_percent: 1.0,
+        sell_fee_percent: 1.25,
+        migration_fee_percent: 0.5,
+        paused: false,
+        is_completed: false,
+    };
+    let mut data = Config::DISCRIMINATOR.to_vec();
+    legacy.serialize(&mut data).unwrap();
+    assert_eq!(data.len(), 8 + LegacyConfig::LEN);
+
+    let config = LegacyConfig::migrate(&data).unwrap();
+    assert_eq!((config.buy_fee_bps, config.sell_fee_bps, config.migration_fee_bps), (100, 125, 50));
+    assert_eq!(config.tax_treasury, fee_recipient);
+
+    //  the new layout fills the account it grows into, and needs more rent than the legacy one
+    let mut migrated = Vec::new();
+    config.try_serialize(&mut migrated).unwrap();
+    assert_eq!(migrated.len(), 8 + Config::LEN);
+    let rent = Rent::default();
+    assert!(rent.minimum_balance(8 + Config::LEN) > rent.minimum_balance(data.len()));
+
+    //  only a legacy sized account migrates
+    let err = LegacyConfig::migrate(&migrated).err().unwrap();
+    assert_eq!(err, PumpError::IncorrectValue.into());
+
+    //  and only into a config that validates
+    let mut data = Config::DISCRIMINATOR.to_vec();
+    LegacyConfig {
+        initial_real_token_reserves: 0,
+        ..legacy
+    }
+    .serialize(&mut data)
+    .unwrap();
+    let err = LegacyConfig::migrate(&data).err().unwrap();
+    assert_eq!(err, PumpError::IncorrectValue.into());
+}
+
+#[tokio::test]
+async fn curve_trades_update_real_reserves() {
+    let curve_limit = 85_000_000_000;
+    let mut curve = dummy_curve();
+
+    curve.apply_buy(1_000_000_000, 30_000_000_000_000, curve_limit).unwrap();
+    assert_eq!(curve.real_sol_reserves, 1_000_000_000);
+    assert_eq!(curve.real_token_reserves, 763_100_000_000_000);
+    assert_eq!(curve.virtual_sol_reserves, 31_000_000_000);
+    assert_eq!(curve.virtual_token_reserves, 1_043_000_000_000_000);
+    assert!(!curve.is_completed);
+
+    curve.apply_sell(10_000_000_000_000, 400_000_000).unwrap();
+    assert_eq!(curve.real_sol_reserves, 600_000_000);
+    assert_eq!(curve.real_token_reserves, 773_100_000_000_000);
+    assert_eq!(curve.virtual_sol_reserves, 30_600_000_000);
+    assert_eq!(curve.virtual_token_reserves, 1_053_000_000_000_000);
+
+    //  a sell can't pay out more sol than the curve took in
+    let err = curve.clone().apply_sell(1, 600_000_001).err().unwrap();
+    assert_eq!(err, PumpError::OverflowOrUnderflowOccurred.into());
+
+    //  nor a buy take more tokens than remain for sale
+    let err = curve.clone().apply_buy(1, 773_100_000_000_001, curve_limit).err().unwrap();
+    assert_eq!(err, PumpError::NotEnoughTokenReserves.into());
+
+    //  the curve completes on reaching the limit, with the virtual sol still below it
+    let mut limited = curve.clone();
+    limited.apply_buy(curve_limit - 600_000_000, 1, curve_limit).unwrap();
+    assert!(limited.is_completed);
+    assert!(limited.virtual_sol_reserves > curve_limit);
+
+    //  or on selling out
+    let mut sold_out = curve.clone();
+    sold_out.apply_buy(1, 773_100_000_000_000, curve_limit).unwrap();
+    assert_eq!(sold_out.real_token_reserves, 0);
+    assert!(sold_out.is_completed);
+}
+
+#[tokio::test]
+async fn curve_early_window_starts_at_open() {
+    let mut curve = dummy_curve();
+    curve.trading_starts_at = 1_700_000_000;
+    curve.presale_ends_at = 1_700_003_600;
+
+    //  launched at slot 100, long before trading opens
+    curve.mark_opened(1_699_990_000, 100);
+    assert_eq!(curve.opened_slot, 0);
+    assert!(!curve.is_early(100, 150));
+
+    //  the presale trades but doesn't open public trading, nor the early window
+    curve.mark_opened(1_700_000_000, 100_000);
+    assert_eq!(curve.opened_slot, 0);
+    assert!(!curve.is_early(100_000, 150));
+
+    //  the first swap after the presale opens the window, later swaps keep it
+    curve.mark_opened(1_700_003_600, 110_000);
+    curve.mark_opened(1_700_003_700, 110_100);
+    assert_eq!(curve.opened_slot, 110_000);
+    assert!(curve.is_early(110_149, 150));
+    assert!(!curve.is_early(110_150, 150));
+
+    //  no window configured
+    assert!(!curve.is_early(100, 0));
+}
+
+#[tokio::test]
+async fn curve_presale_buys_skip_the_early_cap() {
+    let mut cfg = dummy_config(false, false, Pubkey::new_unique());
+    cfg.early_window_slots = 150;
+    cfg.early_wallet_cap = 1_000;
+    cfg.validate().unwrap();
+
+    let mut curve = dummy_curve();
+    curve.trading_starts_at = 1_700_000_000;
+    curve.presale_ends_at = 1_700_003_600;
+    curve.mark_opened(1_700_000_000, 100_000);
+    assert!(curve.is_presale(1_700_000_000));
+    assert!(!curve.is_early(100_000, cfg.early_window_slots));
+
+    //  so a presale cap above the early cap is only held to itself
+    let (mint, user) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let (address, _) = CappedPosition::address(PositionKind::Presale, &mint, &user);
+    let mut data = Vec::new();
+    CappedPosition { mint, user, bought: 0 }.try_serialize(&mut data).unwrap();
+    let (mut position_lamports, mut user_lamports, mut system_lamports) = (1_000_000, 0, 0);
+    let (mut user_data, mut system_data) = ([0u8; 0], [0u8; 0]);
+    let position = AccountInfo::new(
+        &address, false, true, &mut position_lamports, &mut data, &pump::ID, false, 0,
+    );
+    let user_info = AccountInfo::new(
+        &user, true, true, &mut user_lamports, &mut user_data, &system_program::ID, false, 0,
+    );
+    let system_info = AccountInfo::new(
+        &system_program::ID, false, false, &mut system_lamports, &mut system_data,
+        &system_program::ID, true, 0,
+    );
+    let presale_cap = 5 * cfg.early_wallet_cap;
+    CappedPosition::track(
+        PositionKind::Presale, &position, &mint, &user_info, &system_info, presale_cap, presale_cap,
+    )
+    .unwrap();
+
+    //  the early cap applies from the first swap after the presale
+    curve.mark_opened(1_700_003_600, 110_000);
+    assert!(curve.is_early(110_000, cfg.early_window_slots));
+}
+
+#[tokio::test]
+async fn capped_position_tracks_each_kind() {
+    let (mint, user) = (Pubkey::new_unique(), Pubkey::new_unique());
+    for (kind, seed, invalid_account, cap_exceeded) in [
+        (
+            PositionKind::Presale,
+            "presale-position",
+            PumpError::InvalidPresaleAccount,
+            PumpError::PresaleCapExceeded,
+        ),
+        (
+            PositionKind::EarlyBuy,
+            "early-buy-position",
+            PumpError::InvalidEarlyBuyAccount,
+            PumpError::EarlyBuyCapExceeded,
+        ),
+    ] {
+        let (address, _) = CappedPosition::address(kind, &mint, &user);
+        let seeds: &[&[u8]] = &[seed.as_bytes(), mint.as_ref(), user.as_ref()];
+        assert_eq!(address, Pubkey::find_program_address(seeds, &pump::ID).0);
+
+        //  an existing position, so tracking only reads and writes its data
+        let mut data = Vec::new();
+        CappedPosition { mint, user, bought: 0 }.try_serialize(&mut data).unwrap();
+        let (mut position_lamports, mut user_lamports, mut system_lamports) = (1_000_000, 0, 0);
+        let (mut user_data, mut system_data) = ([0u8; 0], [0u8; 0]);
+        let wrong_address = Pubkey::new_unique();
+        let mut wrong_lamports = 0;
+        let mut wrong_data = data.clone();
+        let position = AccountInfo::new(
+            &address, false, true, &mut position_lamports, &mut data, &pump::ID, false, 0,
+        );
+        let wrong_position = AccountInfo::new(
+            &wrong_address, false, true, &mut wrong_lamports, &mut wrong_data, &pump::ID, false, 0,
+        );
+        let user_info = AccountInfo::new(
+            &user, true, true, &mut user_lamports, &mut user_data, &system_program::ID, false, 0,
+        );
+        let system_info = AccountInfo::new(
+            &system_program::ID, false, false, &mut system_lamports, &mut system_data,
+            &system_program::ID, true, 0,
+        );
+        let cap = 1_000;
+
+        CappedPosition::track(kind, &position, &mint, &user_info, &system_info, 600, cap).unwrap();
+        CappedPosition::track(kind, &position, &mint, &user_info, &system_info, 400, cap).unwrap();
+        let err = CappedPosition::track(kind, &position, &mint, &user_info, &system_info, 1, cap)
+            .unwrap_err();
+        assert_eq!(err, cap_exceeded.into());
+        let tracked =
+            CappedPosition::try_deserialize(&mut &position.try_borrow_data().unwrap()[..]).unwrap();
+        assert_eq!(tracked.bought, cap);
+
+        let err = CappedPosition::track(kind, &wrong_position, &mint, &user_info, &system_info, 1, cap)
+            .unwrap_err();
+        assert_eq!(err, invalid_account.into());
+    }
+}
@@ -15,10 +15,16 @@ import {
   bondingCurvePda,
   fetchAccountData,
   curveAta,
+  eventAuthorityPda,
+  creatorVaultPda,
+  referralAccounts,
+  presalePositionPda,
+  earlyBuyPositionPda,
+  parseBondingCurve,
 } from './shared';
 
 function help() {
-  console.log('Usage: ts-node --transpile-only scripts/buy.ts --mint <MINT> --lamports <LAMPORTS> [--send]  (env: ANCHOR_PROVIDER_URL, ANCHOR_WALLET)');
+  console.log('Usage: ts-node --transpile-only scripts/buy.ts --mint <MINT> --lamports <LAMPORTS> [--deadline <SECONDS>] [--presaleCap <TOKENS> --presaleProof <HEX,HEX,..>] [--referrer <WALLET>] [--send]  (env: ANCHOR_PROVIDER_URL, ANCHOR_WALLET)');
 }
 
 async function main() {
@@ -41,6 +47,7 @@ async function main() {
   const feeRecipient = cfg.feeRecipient;
 
   const bondingCurve = bondingCurvePda(PROGRAM_ID, mint);
+  const curve = parseBondingCurve(await fetchAccountData(connection, bondingCurve));
   const curveTokenAccount = curveAta(mint, bondingCurve);
   const userTokenAccount = ownerAta(mint, provider.wallet.publicKey);
 
@@ -48,28 +55,57 @@ async function main() {
     user: provider.wallet.publicKey,
     global_config: globalConfig,
     fee_recipient: feeRecipient,
+    tax_treasury: cfg.taxTreasury,
     bonding_curve: bondingCurve,
+    creator_vault: creatorVaultPda(PROGRAM_ID, curve.creator),
     token_mint: mint,
     curve_token_account: curveTokenAccount,
     user_token_account: userTokenAccount,
     token_program: SPL.TOKEN_PROGRAM_ID,
     associated_token_program: SPL.ASSOCIATED_TOKEN_PROGRAM_ID,
     system_program: SYS.SystemProgram.programId,
+    event_authority: eventAuthorityPda(PROGRAM_ID),
+    program: PROGRAM_ID,
   } as any);
 
   const amount = new anchor.BN(lamports);
   const direction = 0; // 0=buy
   const minOut = new anchor.BN(0);
+  const referrer = flags.referrer ? new PublicKey(flags.referrer as string) : undefined;
+  //  optional expiry, --deadline is seconds from now
+  const deadline = flags.deadline !== undefined ? new anchor.BN(Math.floor(Date.now() / 1000) + Number(flags.deadline)) : null;
+
+  //  allowlist entry from scripts/presale-allowlist.ts, needed while the curve is in presale
+  const presale = flags.presaleCap !== undefined
+    ? {
+        cap: new anchor.BN(String(flags.presaleCap)),
+        proof: (typeof flags.presaleProof === 'string' ? flags.presaleProof.split(',') : [])
+          .filter(Boolean)
+          .map((h: string) => Array.from(Buffer.from(h, 'hex'))),
+      }
+    : null;
+  const presaleAccounts = presale
+    ? [{ pubkey: presalePositionPda(PROGRAM_ID, mint, provider.wallet.publicKey), isSigner: false, isWritable: true }]
+    : [];
+
+  //  pass the wallet's early buy position whenever the config has a window, the program ignores it outside the window
+  const earlyBuy = cfg.earlyWindowSlots.gtn(0);
+  const earlyAccounts = earlyBuy
+    ? [{ pubkey: earlyBuyPositionPda(PROGRAM_ID, mint, provider.wallet.publicKey), isSigner: false, isWritable: true }]
+    : [];
 
   const decimals = await getMintDecimals(connection, mint);
 
-  buildPreview('buy', PROGRAM_ID, accounts as any, { amount: amount.toString(), direction, minOut: minOut.toString() }, {
+  buildPreview('buy', PROGRAM_ID, accounts as any, { amount: amount.toString(), direction, minOut: minOut.toString(), deadline: deadline?.toString() ?? null, presaleCap: presale?.cap.toString() ?? null, earlyBuy, referrer: referrer?.toBase58() ?? null }, {
     mint: mint.toBase58(),
     mintDecimals: decimals,
     lamports,
   });
 
-  const builder = (program as any).methods.swap(amount, direction, minOut).accounts(accounts);
+  const builder = (program as any).methods
+    .swap(amount, direction, minOut, deadline, presale, earlyBuy)
+    .accounts(accounts)
+    .remainingAccounts([...presaleAccounts, ...earlyAccounts, ...referralAccounts(PROGRAM_ID, referrer)]);
   if (!flags.send) {
     await builder.instruction();
     console.log('Dry-run. Pass --send to submit.');
@@ -0,0 +1,50 @@
+import {
+  buildAccountsFromIdl,
+  buildPreview,
+  creatorVaultPda,
+  eventAuthorityPda,
+  getInstructionIdl,
+  getProgram,
+  globalConfigPda,
+  parseFlags,
+} from './shared';
+
+function help() {
+  console.log('Usage: ts-node --transpile-only scripts/claim-creator-fees.ts [--send]  (env: ANCHOR_PROVIDER_URL, ANCHOR_WALLET)');
+}
+
+async function main() {
+  const flags = parseFlags(process.argv);
+  if (flags.help) return help();
+
+  const { program, idl, PROGRAM_ID, provider } = getProgram();
+  const creator = provider.wallet.publicKey;
+  const ixIdl = getInstructionIdl(idl, ['claim_creator_fees', 'claimCreatorFees']);
+
+  const creatorVault = creatorVaultPda(PROGRAM_ID, creator);
+  const accounts = buildAccountsFromIdl(ixIdl.accounts, {
+    creator,
+    global_config: globalConfigPda(PROGRAM_ID),
+    creator_vault: creatorVault,
+    event_authority: eventAuthorityPda(PROGRAM_ID),
+    program: PROGRAM_ID,
+  } as any);
+
+  const balance = await provider.connection.getBalance(creatorVault);
+  buildPreview('claim_creator_fees', PROGRAM_ID, accounts as any, {}, { vaultLamports: balance });
+
+  const builder = (program as any).methods[ixIdl.name]().accounts(accounts);
+  if (!flags.send) {
+    await builder.instruction();
+    console.log('Dry-run. Pass --send to submit.');
+    return;
+  }
+  const sig = await builder.rpc();
+  console.log('Signature:', sig);
+}
+
+main().catch((e) => {
+  if (process.argv.includes('--help')) return help();
+  console.error(e);
+  process.exit(1);
+});
@@ -11,7 +11,7 @@ import {
 } from './shared';
 
 function help() {
-  console.log('Usage: ts-node --transpile-only scripts/configure.ts [--fees 0.05] [--send]  (env: ANCHOR_PROVIDER_URL, ANCHOR_WALLET)');
+  console.log('Usage: ts-node --transpile-only scripts/configure.ts [--feeBps 100] [--creatorFeeBps 0] [--referralFeeBps 0] [--launchFeeLamports 0] [--earlyWindowSlots 0 --earlyWalletCap <TOKENS>] [--launchTaxBps 0 --launchTaxDecaySlots 0] [--send]  (env: ANCHOR_PROVIDER_URL, ANCHOR_WALLET)');
 }
 
 async function main() {
@@ -20,7 +20,14 @@ async function main() {
 
   const { program, idl, PROGRAM_ID, provider } = getProgram();
 
-  const fees = typeof flags.fees === 'number' ? flags.fees : 0.05;
+  const feeBps = typeof flags.feeBps === 'number' ? flags.feeBps : 100;
+  const creatorFeeBps = typeof flags.creatorFeeBps === 'number' ? flags.creatorFeeBps : 0;
+  const referralFeeBps = typeof flags.referralFeeBps === 'number' ? flags.referralFeeBps : 0;
+  const launchFeeLamports = new anchor.BN(String(flags.launchFeeLamports ?? 0));
+  const earlyWindowSlots = new anchor.BN(String(flags.earlyWindowSlots ?? 0));
+  const earlyWalletCap = new anchor.BN(String(flags.earlyWalletCap ?? 0));
+  const launchTaxBps = typeof flags.launchTaxBps === 'number' ? flags.launchTaxBps : 0;
+  const launchTaxDecaySlots = new anchor.BN(String(flags.launchTaxDecaySlots ?? 0));
 
   const ixIdl = getInstructionIdl(idl, ['configure']);
 
@@ -33,11 +40,25 @@ async function main() {
     curve_limit: new anchor.BN(5_000_000_000),
     initial_virtual_token_reserves: new anchor.BN(2_000_000_000),
     initial_virtual_sol_reserves: new anchor.BN(1_000_000_000),
-    initial_real_token_reserves: new anchor.BN(0),
+    initial_real_token_reserves: new anchor.BN(1_600_000_000),
     total_token_supply: new anchor.BN(1_000_000_000_000),
-    buy_fee_percent: fees,
-    sell_fee_percent: fees,
-    migration_fee_percent: fees,
+    buy_fee_bps: feeBps,
+    sell_fee_bps: feeBps,
+    migration_fee_bps: feeBps,
+    creator_fee_bps: creatorFeeBps, // share of each trade fee, in bps of the fee
+    referral_fee_bps: referralFeeBps, // referrer share of the platform part of the fee
+    launch_fee_lamports: launchFeeLamports, // charged to non-exempt creators at launch
+    allowed_curve_kinds: 0b111, // constant product, linear, exponential
+    linear_growth_bps: 90_000,
+    exponential_growth_bps: 300,
+    max_name_len: 32, // metadata limits checked at launch, at most 32/10/200
+    max_symbol_len: 10,
+    max_uri_len: 200,
+    early_window_slots: earlyWindowSlots, // anti-sniper window after launch, 0 turns it off
+    early_wallet_cap: earlyWalletCap, // tokens per wallet and curve during the window
+    launch_tax_bps: launchTaxBps, // buy fee until public trading opens, decays to buy_fee_bps
+    launch_tax_decay_slots: launchTaxDecaySlots,
+    tax_treasury: provider.wallet.publicKey, // receives the part of the fee above buy_fee_bps
   };
 
   const accounts = buildAccountsFromIdl(ixIdl.accounts, {
@@ -42,6 +42,7 @@ async function main() {
       totalSupply: parsed.tokenTotalSupply.toString(),
     },
     isCompleted: parsed.isCompleted,
+    tradingStartsAt: parsed.tradingStartsAt,
   }));
 }
 
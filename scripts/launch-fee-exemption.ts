@@ -0,0 +1,53 @@
+import { PublicKey } from '@solana/web3.js';
+import {
+  buildAccountsFromIdl,
+  buildPreview,
+  getInstructionIdl,
+  getProgram,
+  globalConfigPda,
+  launchFeeExemptionPda,
+  parseFlags,
+  requireFlag,
+  SYS,
+} from './shared';
+
+function help() {
+  console.log('Usage: ts-node --transpile-only scripts/launch-fee-exemption.ts --creator <WALLET> [--remove] [--send]  (env: ANCHOR_PROVIDER_URL, ANCHOR_WALLET)');
+}
+
+async function main() {
+  const flags = parseFlags(process.argv);
+  if (flags.help) return help();
+
+  const creator = new PublicKey(requireFlag<string>(flags, 'creator', 'Missing --creator'));
+  const remove = Boolean(flags.remove);
+
+  const { program, idl, PROGRAM_ID, provider } = getProgram();
+  const ixIdl = remove
+    ? getInstructionIdl(idl, ['remove_launch_fee_exemption', 'removeLaunchFeeExemption'])
+    : getInstructionIdl(idl, ['add_launch_fee_exemption', 'addLaunchFeeExemption']);
+
+  const accounts = buildAccountsFromIdl(ixIdl.accounts, {
+    admin: provider.wallet.publicKey,
+    global_config: globalConfigPda(PROGRAM_ID),
+    launch_fee_exemption: launchFeeExemptionPda(PROGRAM_ID, creator),
+    system_program: SYS.SystemProgram.programId,
+  } as any);
+
+  buildPreview(ixIdl.name, PROGRAM_ID, accounts as any, { creator: creator.toBase58() });
+
+  const builder = (program as any).methods[ixIdl.name](creator).accounts(accounts);
+  if (!flags.send) {
+    await builder.instruction();
+    console.log('Dry-run. Pass --send to submit.');
+    return;
+  }
+  const sig = await builder.rpc();
+  console.log('Signature:', sig);
+}
+
+main().catch((e) => {
+  if (process.argv.includes('--help')) return help();
+  console.error(e);
+  process.exit(1);
+});
@@ -3,17 +3,25 @@ import { Keypair, PublicKey, SYSVAR_RENT_PUBKEY } from '@solana/web3.js';
 import {
   buildAccountsFromIdl,
   buildPreview,
+  eventAuthorityPda,
+  fetchAccountData,
   getInstructionIdl,
   getProgram,
   globalConfigPda,
+  launchFeeExemptionPda,
+  parseConfig,
   parseFlags,
+  requireFlag,
   curveAta,
+  ownerAta,
+  creatorVaultPda,
+  earlyBuyPositionPda,
   SPL,
   SYS,
 } from './shared';
 
 function help() {
-  console.log('Usage: ts-node --transpile-only scripts/launch.ts [--name NAME --symbol SYM --uri URL] [--send]  (env: ANCHOR_PROVIDER_URL, ANCHOR_WALLET)');
+  console.log('Usage: ts-node --transpile-only scripts/launch.ts [--name NAME --symbol SYM --uri URL] [--curve constantProduct|linear|exponential] [--tradingStartsIn <SECONDS>] [--presaleEndsIn <SECONDS> --presaleRoot <HEX>] [--initialBuyLamports <LAMPORTS> --minTokensOut <TOKENS> --deadline <SECONDS>] [--send]  (env: ANCHOR_PROVIDER_URL, ANCHOR_WALLET)');
 }
 
 async function main() {
@@ -23,6 +31,20 @@ async function main() {
   const name = (flags.name as string) || 'TestToken';
   const symbol = (flags.symbol as string) || 'TST';
   const uri = (flags.uri as string) || 'https://example.com/metadata.json';
+  const curve = (flags.curve as string) || 'constantProduct';
+  //  optional scheduled start, --tradingStartsIn is seconds from now
+  const tradingStartsAt = flags.tradingStartsIn !== undefined ? new anchor.BN(Math.floor(Date.now() / 1000) + Number(flags.tradingStartsIn)) : null;
+  //  optional presale, the root comes from scripts/presale-allowlist.ts
+  const presale = flags.presaleEndsIn !== undefined
+    ? {
+        endsAt: new anchor.BN(Math.floor(Date.now() / 1000) + Number(flags.presaleEndsIn)),
+        merkleRoot: Array.from(Buffer.from(requireFlag<string>(flags, 'presaleRoot', 'Missing --presaleRoot'), 'hex')),
+      }
+    : null;
+  //  optional dev buy executed in the launch transaction
+  const initialBuy = flags.initialBuyLamports !== undefined ? new anchor.BN(String(flags.initialBuyLamports)) : null;
+  const minTokensOut = new anchor.BN(String(flags.minTokensOut ?? 0));
+  const deadline = flags.deadline !== undefined ? new anchor.BN(Math.floor(Date.now() / 1000) + Number(flags.deadline)) : null;
 
   const { program, idl, PROGRAM_ID, provider } = getProgram();
 
@@ -30,6 +52,7 @@ async function main() {
 
   const tokenMint = Keypair.generate();
   const globalConfig = globalConfigPda(PROGRAM_ID);
+  const cfg = parseConfig(await fetchAccountData(provider.connection, globalConfig));
 
   // compute bonding curve PDA idempotently
   const [bondingCurvePk] = PublicKey.findProgramAddressSync([
@@ -53,22 +76,31 @@ async function main() {
   const accounts = buildAccountsFromIdl(ixIdl.accounts, {
     creator: provider.wallet.publicKey,
     global_config: globalConfig,
+    fee_recipient: cfg.feeRecipient,
+    tax_treasury: cfg.taxTreasury,
     token_mint: tokenMint.publicKey,
     bonding_curve: bondingCurvePk,
     curve_token_account: curveTokenAccount,
+    creator_token_account: ownerAta(tokenMint.publicKey, provider.wallet.publicKey),
+    creator_early_buy_position: earlyBuyPositionPda(PROGRAM_ID, tokenMint.publicKey, provider.wallet.publicKey),
+    creator_vault: creatorVaultPda(PROGRAM_ID, provider.wallet.publicKey),
+    launch_fee_exemption: launchFeeExemptionPda(PROGRAM_ID, provider.wallet.publicKey),
     token_metadata_account: tokenMetadataAccount,
     token_program: SPL.TOKEN_PROGRAM_ID,
     associated_token_program: SPL.ASSOCIATED_TOKEN_PROGRAM_ID,
     metadata_program: METADATA_PROGRAM_ID,
     system_program: SYS.SystemProgram.programId,
     rent: SYSVAR_RENT_PUBKEY,
+    event_authority: eventAuthorityPda(PROGRAM_ID),
+    program: PROGRAM_ID,
   } as any);
 
-  buildPreview('launch', PROGRAM_ID, accounts as any, { name, symbol, uri }, {
+  buildPreview('launch', PROGRAM_ID, accounts as any, { name, symbol, uri, curve, tradingStartsAt: tradingStartsAt?.toString() ?? null, presaleEndsAt: presale?.endsAt.toString() ?? null, initialBuy: initialBuy?.toString() ?? null, minTokensOut: minTokensOut.toString(), deadline: deadline?.toString() ?? null }, {
     mint: tokenMint.publicKey.toBase58(),
+    launchFeeLamports: cfg.launchFeeLamports.toString(),
   });
 
-  const builder = (program as any).methods.launch(name, symbol, uri).accounts(accounts).signers([tokenMint]);
+  const builder = (program as any).methods.launch(name, symbol, uri, { [curve]: {} }, tradingStartsAt, presale, initialBuy, minTokensOut, deadline).accounts(accounts).signers([tokenMint]);
   if (!flags.send) {
     await builder.instruction();
     console.log('Dry-run. Pass --send to submit.');
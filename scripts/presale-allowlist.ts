@@ -0,0 +1,36 @@
+import * as anchor from '@coral-xyz/anchor';
+import { PublicKey } from '@solana/web3.js';
+import { readFileSync } from 'fs';
+import { parseFlags, presaleTree, requireFlag } from './shared';
+
+function help() {
+  console.log('Usage: ts-node --transpile-only scripts/presale-allowlist.ts --file <ALLOWLIST.json>  (file: [{ "wallet": "<WALLET>", "cap": "<TOKENS>" }])');
+}
+
+//  prints the Merkle root to launch with and each wallet's cap and proof to buy with
+async function main() {
+  const flags = parseFlags(process.argv);
+  if (flags.help) return help();
+
+  const file = requireFlag<string>(flags, 'file', 'Missing --file');
+  const entries = (JSON.parse(readFileSync(file, 'utf8')) as Array<{ wallet: string; cap: string | number }>).map((e) => ({
+    wallet: new PublicKey(e.wallet),
+    cap: new anchor.BN(String(e.cap)),
+  }));
+
+  const { root, proofs } = presaleTree(entries);
+  console.log(JSON.stringify({
+    root: root.toString('hex'),
+    entries: entries.map((e, i) => ({
+      wallet: e.wallet.toBase58(),
+      cap: e.cap.toString(),
+      proof: proofs[i].map((p) => p.toString('hex')).join(','),
+    })),
+  }, null, 2));
+}
+
+main().catch((e) => {
+  if (process.argv.includes('--help')) return help();
+  console.error(e);
+  process.exit(1);
+});
@@ -0,0 +1,62 @@
+import * as anchor from '@coral-xyz/anchor';
+import { PublicKey } from '@solana/web3.js';
+import {
+  buildAccountsFromIdl,
+  buildPreview,
+  getInstructionIdl,
+  getProgram,
+  globalConfigPda,
+  parseFlags,
+  bondingCurvePda,
+} from './shared';
+
+function help() {
+  console.log('Usage: ts-node --transpile-only scripts/quote.ts --mint <MINT> --amount <AMOUNT> [--sell]  (env: ANCHOR_PROVIDER_URL, ANCHOR_WALLET)');
+  console.log('  amount is lamports for a buy, raw token units for a sell');
+}
+
+async function main() {
+  const flags = parseFlags(process.argv);
+  if (flags.help) return help();
+
+  const mintStr = flags.mint as string;
+  const amountFlag = flags.amount;
+  if (!mintStr || amountFlag === undefined) return help();
+
+  const { program, idl, PROGRAM_ID } = getProgram();
+
+  const mint = new PublicKey(mintStr);
+  const ixIdl = getInstructionIdl(idl, ['quote']);
+
+  const accounts = buildAccountsFromIdl(ixIdl.accounts, {
+    global_config: globalConfigPda(PROGRAM_ID),
+    token_mint: mint,
+    bonding_curve: bondingCurvePda(PROGRAM_ID, mint),
+  } as any);
+
+  const amount = new anchor.BN(String(amountFlag));
+  const direction = flags.sell ? 1 : 0; // 0=buy, 1=sell
+
+  buildPreview('quote', PROGRAM_ID, accounts as any, { amount: amount.toString(), direction });
+
+  //  simulated, nothing is sent
+  const quote = await (program as any).methods.quote(amount, direction).accounts(accounts).view();
+  console.log(
+    JSON.stringify(
+      {
+        amountIn: quote.amountIn.toString(),
+        amountOut: quote.amountOut.toString(),
+        fee: quote.fee.toString(),
+        priceAfter: quote.priceAfter.toString(),
+      },
+      null,
+      2
+    )
+  );
+}
+
+main().catch((e) => {
+  if (process.argv.includes('--help')) return help();
+  console.error(e);
+  process.exit(1);
+});
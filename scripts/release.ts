@@ -4,6 +4,7 @@ import {
   buildPreview,
   bondingCurvePda,
   curveAta,
+  eventAuthorityPda,
   getInstructionIdl,
   getProgram,
   globalConfigPda,
@@ -47,6 +48,8 @@ async function main() {
     token_program: SPL.TOKEN_PROGRAM_ID,
     associated_token_program: SPL.ASSOCIATED_TOKEN_PROGRAM_ID,
     system_program: SYS.SystemProgram.programId,
+    event_authority: eventAuthorityPda(PROGRAM_ID),
+    program: PROGRAM_ID,
   } as any);
 
   buildPreview('release_reserves', PROGRAM_ID, accounts as any, {});
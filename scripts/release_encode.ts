@@ -36,6 +36,10 @@ async function main() {
     [Buffer.from('bonding-curve'), mint.toBuffer()],
     PROGRAM_ID
   );
+  const [eventAuthority] = PublicKey.findProgramAddressSync(
+    [Buffer.from('__event_authority')],
+    PROGRAM_ID
+  );
 
   const admin = provider ? (provider.wallet as any).publicKey as PublicKey : Keypair.generate().publicKey;
   const recipient = process.env.RECIPIENT ? new PublicKey(process.env.RECIPIENT) : admin;
@@ -72,6 +76,8 @@ async function main() {
     { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
     { pubkey: ASSOCIATED_TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
     { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    { pubkey: eventAuthority, isSigner: false, isWritable: false },
+    { pubkey: PROGRAM_ID, isSigner: false, isWritable: false },
   ];
 
   const ix = new TransactionInstruction({ programId: PROGRAM_ID, keys, data });
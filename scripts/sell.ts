@@ -12,12 +12,16 @@ import {
   parseFlags,
   SPL,
   SYS,
+  eventAuthorityPda,
+  creatorVaultPda,
+  referralAccounts,
+  parseBondingCurve,
   bondingCurvePda,
   fetchAccountData,
 } from './shared';
 
 function help() {
-  console.log('Usage: ts-node --transpile-only scripts/sell.ts --mint <MINT> --rawTokens <RAW_UNITS> [--send]  (env: ANCHOR_PROVIDER_URL, ANCHOR_WALLET)');
+  console.log('Usage: ts-node --transpile-only scripts/sell.ts --mint <MINT> --rawTokens <RAW_UNITS> [--deadline <SECONDS>] [--referrer <WALLET>] [--send]  (env: ANCHOR_PROVIDER_URL, ANCHOR_WALLET)');
 }
 
 async function main() {
@@ -40,6 +44,7 @@ async function main() {
   const feeRecipient = cfg.feeRecipient;
 
   const bondingCurve = bondingCurvePda(PROGRAM_ID, mint);
+  const curve = parseBondingCurve(await fetchAccountData(connection, bondingCurve));
   const curveTokenAccount = ownerAta(mint, bondingCurve);
   const userTokenAccount = ownerAta(mint, provider.wallet.publicKey);
 
@@ -47,18 +52,25 @@ async function main() {
     user: provider.wallet.publicKey,
     global_config: globalConfig,
     fee_recipient: feeRecipient,
+    tax_treasury: cfg.taxTreasury,
     bonding_curve: bondingCurve,
+    creator_vault: creatorVaultPda(PROGRAM_ID, curve.creator),
     token_mint: mint,
     curve_token_account: curveTokenAccount,
     user_token_account: userTokenAccount,
     token_program: SPL.TOKEN_PROGRAM_ID,
     associated_token_program: SPL.ASSOCIATED_TOKEN_PROGRAM_ID,
     system_program: SYS.SystemProgram.programId,
+    event_authority: eventAuthorityPda(PROGRAM_ID),
+    program: PROGRAM_ID,
   } as any);
 
   const amount = new anchor.BN(rawTokensStr);
   const direction = 1; // 1=sell
   const minOut = new anchor.BN(0);
+  const referrer = flags.referrer ? new PublicKey(flags.referrer as string) : undefined;
+  //  optional expiry, --deadline is seconds from now
+  const deadline = flags.deadline !== undefined ? new anchor.BN(Math.floor(Date.now() / 1000) + Number(flags.deadline)) : null;
 
   const decimals = await getMintDecimals(connection, mint);
 
@@ -68,7 +80,10 @@ async function main() {
     rawTokens: amount.toString(),
   });
 
-  const builder = (program as any).methods.swap(amount, direction, minOut).accounts(accounts);
+  const builder = (program as any).methods
+    .swap(amount, direction, minOut, deadline, null, false)
+    .accounts(accounts)
+    .remainingAccounts(referralAccounts(PROGRAM_ID, referrer));
   if (!flags.send) {
     await builder.instruction();
     console.log('Dry-run. Pass --send to submit.');
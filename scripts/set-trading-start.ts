@@ -0,0 +1,63 @@
+import * as anchor from '@coral-xyz/anchor';
+import { PublicKey } from '@solana/web3.js';
+import {
+  bondingCurvePda,
+  buildAccountsFromIdl,
+  buildPreview,
+  eventAuthorityPda,
+  fetchAccountData,
+  getInstructionIdl,
+  getProgram,
+  globalConfigPda,
+  parseBondingCurve,
+  parseFlags,
+  requireFlag,
+} from './shared';
+
+function help() {
+  console.log('Usage: ts-node --transpile-only scripts/set-trading-start.ts --mint <MINT> --startsIn <SECONDS> [--send]  (env: ANCHOR_PROVIDER_URL, ANCHOR_WALLET)');
+}
+
+async function main() {
+  const flags = parseFlags(process.argv);
+  if (flags.help) return help();
+
+  const mint = new PublicKey(requireFlag<string>(flags, 'mint', 'Missing --mint'));
+  //  seconds from now, zero or less opens trading right away
+  const startsIn = Number(requireFlag<number>(flags, 'startsIn', 'Missing --startsIn'));
+  const tradingStartsAt = new anchor.BN(Math.floor(Date.now() / 1000) + startsIn);
+
+  const { program, idl, PROGRAM_ID, provider } = getProgram();
+  const ixIdl = getInstructionIdl(idl, ['set_trading_start', 'setTradingStart']);
+
+  const bondingCurve = bondingCurvePda(PROGRAM_ID, mint);
+  const curve = parseBondingCurve(await fetchAccountData(provider.connection, bondingCurve));
+
+  const accounts = buildAccountsFromIdl(ixIdl.accounts, {
+    creator: provider.wallet.publicKey,
+    global_config: globalConfigPda(PROGRAM_ID),
+    token_mint: mint,
+    bonding_curve: bondingCurve,
+    event_authority: eventAuthorityPda(PROGRAM_ID),
+    program: PROGRAM_ID,
+  } as any);
+
+  buildPreview('set_trading_start', PROGRAM_ID, accounts as any, { tradingStartsAt: tradingStartsAt.toString() }, {
+    currentTradingStartsAt: curve.tradingStartsAt,
+  });
+
+  const builder = (program as any).methods[ixIdl.name](tradingStartsAt).accounts(accounts);
+  if (!flags.send) {
+    await builder.instruction();
+    console.log('Dry-run. Pass --send to submit.');
+    return;
+  }
+  const sig = await builder.rpc();
+  console.log('Signature:', sig);
+}
+
+main().catch((e) => {
+  if (process.argv.includes('--help')) return help();
+  console.error(e);
+  process.exit(1);
+});
@@ -1,5 +1,6 @@
 import * as anchor from '@coral-xyz/anchor';
 import { Connection, Keypair, PublicKey, SystemProgram, Transaction, TransactionInstruction } from '@solana/web3.js';
+import { createHash } from 'crypto';
 import { readFileSync } from 'fs';
 import {
   ASSOCIATED_TOKEN_PROGRAM_ID,
@@ -52,6 +53,69 @@ export function bondingCurvePda(programId: PublicKey, mint: PublicKey): PublicKe
   return findPda(['bonding-curve', mint], programId)[0];
 }
 
+export function creatorVaultPda(programId: PublicKey, creator: PublicKey): PublicKey {
+  return findPda(['creator-vault', creator], programId)[0];
+}
+
+export function launchFeeExemptionPda(programId: PublicKey, creator: PublicKey): PublicKey {
+  return findPda(['launch-fee-exemption', creator], programId)[0];
+}
+
+export function presalePositionPda(programId: PublicKey, mint: PublicKey, user: PublicKey): PublicKey {
+  return findPda(['presale-position', mint, user], programId)[0];
+}
+
+//  leaf of a presale allowlist entry, same as utils::presale_leaf in the program
+export function presaleLeaf(wallet: PublicKey, cap: anchor.BN): Buffer {
+  return createHash('sha256').update(wallet.toBuffer()).update(cap.toArrayLike(Buffer, 'le', 8)).digest();
+}
+
+//  Merkle tree over the allowlist with sorted pair hashing, an odd node is carried up as is
+export function presaleTree(entries: Array<{ wallet: PublicKey; cap: anchor.BN }>): { root: Buffer; proofs: Buffer[][] } {
+  if (entries.length === 0) throw new Error('Presale allowlist is empty');
+  const hashPair = (a: Buffer, b: Buffer) =>
+    createHash('sha256').update(Buffer.compare(a, b) <= 0 ? Buffer.concat([a, b]) : Buffer.concat([b, a])).digest();
+
+  let level = entries.map((e) => presaleLeaf(e.wallet, e.cap));
+  let positions = entries.map((_, i) => i);
+  const proofs: Buffer[][] = entries.map(() => []);
+  while (level.length > 1) {
+    const next: Buffer[] = [];
+    for (let i = 0; i < level.length; i += 2) {
+      next.push(i + 1 < level.length ? hashPair(level[i], level[i + 1]) : level[i]);
+    }
+    positions = positions.map((pos, leaf) => {
+      const sibling = pos ^ 1;
+      if (sibling < level.length) proofs[leaf].push(level[sibling]);
+      return pos >> 1;
+    });
+    level = next;
+  }
+  return { root: level[0], proofs };
+}
+
+export function earlyBuyPositionPda(programId: PublicKey, mint: PublicKey, user: PublicKey): PublicKey {
+  return findPda(['early-buy-position', mint, user], programId)[0];
+}
+
+export function referralStatsPda(programId: PublicKey, referrer: PublicKey): PublicKey {
+  return findPda(['referral-stats', referrer], programId)[0];
+}
+
+//  optional swap referral, passed as remaining accounts
+export function referralAccounts(programId: PublicKey, referrer?: PublicKey) {
+  if (!referrer) return [];
+  return [
+    { pubkey: referrer, isSigner: false, isWritable: true },
+    { pubkey: referralStatsPda(programId, referrer), isSigner: false, isWritable: true },
+  ];
+}
+
+//  signer of the self-CPI that carries #[event_cpi] events
+export function eventAuthorityPda(programId: PublicKey): PublicKey {
+  return findPda(['__event_authority'], programId)[0];
+}
+
 export function curveAta(mint: PublicKey, curve: PublicKey): PublicKey {
   return getAssociatedTokenAddressSync(mint, curve, true, TOKEN_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID);
 }
@@ -142,9 +206,25 @@ export function parseConfig(data: Buffer): {
   initialVirtualSolReserves: anchor.BN;
   initialRealTokenReserves: anchor.BN;
   totalTokenSupply: anchor.BN;
-  buyFeePercent: number;
-  sellFeePercent: number;
-  migrationFeePercent: number;
+  buyFeeBps: number;
+  sellFeeBps: number;
+  migrationFeeBps: number;
+  creatorFeeBps: number;
+  referralFeeBps: number;
+  launchFeeLamports: anchor.BN;
+  paused: boolean;
+  isCompleted: boolean;
+  allowedCurveKinds: number;
+  linearGrowthBps: number;
+  exponentialGrowthBps: number;
+  maxNameLen: number;
+  maxSymbolLen: number;
+  maxUriLen: number;
+  earlyWindowSlots: anchor.BN;
+  earlyWalletCap: anchor.BN;
+  launchTaxBps: number;
+  launchTaxDecaySlots: anchor.BN;
+  taxTreasury: PublicKey;
 } {
   let o = 8; // discriminator
   const readPub = () => {
@@ -157,9 +237,19 @@ export function parseConfig(data: Buffer): {
     o += 8;
     return v;
   };
-  const readF64 = () => {
-    const v = data.readDoubleLE(o);
-    o += 8;
+  const readU16 = () => {
+    const v = data.readUInt16LE(o);
+    o += 2;
+    return v;
+  };
+  const readU8 = () => {
+    const v = data[o];
+    o += 1;
+    return v;
+  };
+  const readU32 = () => {
+    const v = data.readUInt32LE(o);
+    o += 4;
     return v;
   };
   const authority = readPub();
@@ -169,9 +259,25 @@ export function parseConfig(data: Buffer): {
   const initialVirtualSolReserves = readU64();
   const initialRealTokenReserves = readU64();
   const totalTokenSupply = readU64();
-  const buyFeePercent = readF64();
-  const sellFeePercent = readF64();
-  const migrationFeePercent = readF64();
+  const buyFeeBps = readU16();
+  const sellFeeBps = readU16();
+  const migrationFeeBps = readU16();
+  const creatorFeeBps = readU16();
+  const referralFeeBps = readU16();
+  const launchFeeLamports = readU64();
+  const paused = readU8() !== 0;
+  const isCompleted = readU8() !== 0;
+  const allowedCurveKinds = readU8();
+  const linearGrowthBps = readU32();
+  const exponentialGrowthBps = readU32();
+  const maxNameLen = readU8();
+  const maxSymbolLen = readU8();
+  const maxUriLen = readU16();
+  const earlyWindowSlots = readU64();
+  const earlyWalletCap = readU64();
+  const launchTaxBps = readU16();
+  const launchTaxDecaySlots = readU64();
+  const taxTreasury = readPub();
   return {
     authority,
     feeRecipient,
@@ -180,9 +286,25 @@ export function parseConfig(data: Buffer): {
     initialVirtualSolReserves,
     initialRealTokenReserves,
     totalTokenSupply,
-    buyFeePercent,
-    sellFeePercent,
-    migrationFeePercent,
+    buyFeeBps,
+    sellFeeBps,
+    migrationFeeBps,
+    creatorFeeBps,
+    referralFeeBps,
+    launchFeeLamports,
+    paused,
+    isCompleted,
+    allowedCurveKinds,
+    linearGrowthBps,
+    exponentialGrowthBps,
+    maxNameLen,
+    maxSymbolLen,
+    maxUriLen,
+    earlyWindowSlots,
+    earlyWalletCap,
+    launchTaxBps,
+    launchTaxDecaySlots,
+    taxTreasury,
   };
 }
 
@@ -193,8 +315,21 @@ export function parseBondingCurve(data: Buffer): {
   realSolReserves: anchor.BN;
   tokenTotalSupply: anchor.BN;
   isCompleted: boolean;
+  creator: PublicKey;
+  curveKind: number;
+  curveGrowthBps: number;
+  initialRealTokenReserves: anchor.BN;
+  tradingStartsAt: number;
+  presaleEndsAt: number;
+  presaleMerkleRoot: Buffer;
+  openedSlot: anchor.BN;
 } {
   let o = 8; // discriminator
+  const readPub = () => {
+    const pk = new PublicKey(data.slice(o, o + 32));
+    o += 32;
+    return pk;
+  };
   const readU64 = () => {
     const v = new anchor.BN(data.slice(o, o + 8), 10, 'le');
     o += 8;
@@ -205,12 +340,36 @@ export function parseBondingCurve(data: Buffer): {
     o += 1;
     return v;
   };
+  const readU8 = () => {
+    const v = data[o];
+    o += 1;
+    return v;
+  };
+  const readU32 = () => {
+    const v = data.readUInt32LE(o);
+    o += 4;
+    return v;
+  };
+  const readI64 = () => {
+    const v = Number(data.readBigInt64LE(o));
+    o += 8;
+    return v;
+  };
   const virtualTokenReserves = readU64();
   const virtualSolReserves = readU64();
   const realTokenReserves = readU64();
   const realSolReserves = readU64();
   const tokenTotalSupply = readU64();
   const isCompleted = readBool();
+  const creator = readPub();
+  const curveKind = readU8();
+  const curveGrowthBps = readU32();
+  const initialRealTokenReserves = readU64();
+  const tradingStartsAt = readI64();
+  const presaleEndsAt = readI64();
+  const presaleMerkleRoot = Buffer.from(data.slice(o, o + 32));
+  o += 32;
+  const openedSlot = readU64();
   return {
     virtualTokenReserves,
     virtualSolReserves,
@@ -218,6 +377,14 @@ export function parseBondingCurve(data: Buffer): {
     realSolReserves,
     tokenTotalSupply,
     isCompleted,
+    creator,
+    curveKind,
+    curveGrowthBps,
+    initialRealTokenReserves,
+    tradingStartsAt,
+    presaleEndsAt,
+    presaleMerkleRoot,
+    openedSlot,
   };
 }
 
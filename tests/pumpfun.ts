@@ -272,7 +272,7 @@ describe("pumpfun", () => {
     // case 1: failed because minimum receive is too high because of slippage
     try {
       await program.methods
-        .swap(new BN(5_000_000), 0, new BN(5_000_000_0))
+        .swap(new BN(5_000_000), 0, new BN(5_000_000_0), null, null, false)
         .accounts({
           teamWallet: configAccount.teamWallet,
           user: userKp.publicKey,
@@ -289,7 +289,7 @@ describe("pumpfun", () => {
 
     // case 2: happy case. Send the transaction to launch a token
     const tx = await program.methods
-      .swap(new BN(5_000_000), 0, new BN(0))
+      .swap(new BN(5_000_000), 0, new BN(0), null, null, false)
       .accounts({
         teamWallet: configAccount.teamWallet,
         user: userKp.publicKey,
@@ -322,7 +322,7 @@ describe("pumpfun", () => {
 
     // Send the transaction to launch a token
     const tx = await program.methods
-      .swap(new BN(22_000_000), 1, new BN(0))
+      .swap(new BN(22_000_000), 1, new BN(0), null, null, false)
       .accounts({
         teamWallet: configAccount.teamWallet,
         user: userKp.publicKey,
@@ -355,7 +355,7 @@ describe("pumpfun", () => {
 
     // Send the transaction to launch a token
     const tx = await program.methods
-      .swap(new BN(4_000_000_000), 0, new BN(0))
+      .swap(new BN(4_000_000_000), 0, new BN(0), null, null, false)
       .accounts({
         teamWallet: configAccount.teamWallet,
         user: user2Kp.publicKey,
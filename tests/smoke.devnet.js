@@ -19,6 +19,9 @@ function globalConfigPda(programId) {
 function bondingCurvePda(programId, mint) {
   return PublicKey.findProgramAddressSync([Buffer.from('bonding-curve'), mint.toBuffer()], programId)[0];
 }
+function earlyBuyPositionPda(programId, mint, user) {
+  return PublicKey.findProgramAddressSync([Buffer.from('early-buy-position'), mint.toBuffer(), user.toBuffer()], programId)[0];
+}
 function metadataPda(mint) {
   return PublicKey.findProgramAddressSync([
     Buffer.from('metadata'),
@@ -66,12 +69,26 @@ describe('devnet smoke: configure → launch → buy until completion → releas
       feeRecipient: admin.publicKey,
       curveLimit: new anchor.BN(1_200_000),
       initialVirtualTokenReserves: new anchor.BN(500_000_000_000),
-      initialVirtualSolReserves: new anchor.BN(0),
-      initialRealTokenReserves: new anchor.BN(0),
+      initialVirtualSolReserves: new anchor.BN(30_000_000),
+      initialRealTokenReserves: new anchor.BN(400_000_000_000),
       totalTokenSupply: new anchor.BN(1_000_000_000_000),
-      buyFeePercent: 0,
-      sellFeePercent: 0,
-      migrationFeePercent: 0,
+      buyFeeBps: 0,
+      sellFeeBps: 0,
+      migrationFeeBps: 0,
+      creatorFeeBps: 0,
+      referralFeeBps: 0,
+      launchFeeLamports: new anchor.BN(0),
+      allowedCurveKinds: 1, // constant product only
+      linearGrowthBps: 0,
+      exponentialGrowthBps: 0,
+      maxNameLen: 32,
+      maxSymbolLen: 10,
+      maxUriLen: 200,
+      earlyWindowSlots: new anchor.BN(0),
+      earlyWalletCap: new anchor.BN(0),
+      launchTaxBps: 0,
+      launchTaxDecaySlots: new anchor.BN(0),
+      taxTreasury: admin.publicKey,
     };
 
     const globalConfig = globalConfigPda(programId);
@@ -98,7 +115,7 @@ describe('devnet smoke: configure → launch → buy until completion → releas
     const tokenMetadataAccount = metadataPda(tokenMint.publicKey);
 
     await program.methods
-      .launch('SmokeToken', 'SMK', 'https://example.com/smoke.json')
+      .launch('SmokeToken', 'SMK', 'https://example.com/smoke.json', { constantProduct: {} }, null, null, null, new anchor.BN(0), null)
       .accounts({
         creator: admin.publicKey,
         globalConfig,
@@ -106,6 +123,10 @@ describe('devnet smoke: configure → launch → buy until completion → releas
         bondingCurve,
         curveTokenAccount,
         tokenMetadataAccount,
+        feeRecipient: admin.publicKey,
+        taxTreasury: currentConfig.taxTreasury,
+        creatorTokenAccount: getAssociatedTokenAddressSync(tokenMint.publicKey, admin.publicKey, false, TOKEN_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID),
+        creatorEarlyBuyPosition: earlyBuyPositionPda(programId, tokenMint.publicKey, admin.publicKey),
         tokenProgram: TOKEN_PROGRAM_ID,
         associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         metadataProgram: METADATA_PROGRAM_ID,
@@ -123,11 +144,12 @@ describe('devnet smoke: configure → launch → buy until completion → releas
     const limitBn = currentConfig.curveLimit; // Anchor returns BN
 
     await program.methods
-      .swap(limitBn, 0, new anchor.BN(0))
+      .swap(limitBn, 0, new anchor.BN(0), null, null, false)
       .accounts({
         user: buyer.publicKey,
         globalConfig,
         feeRecipient,
+        taxTreasury: currentConfig.taxTreasury,
         bondingCurve,
         tokenMint: tokenMint.publicKey,
         curveTokenAccount,
@@ -200,7 +222,7 @@ describe('devnet smoke: configure → launch → buy until completion → releas
     const tokenMetadataAccount = metadataPda(tokenMint.publicKey);
 
     await program.methods
-      .launch('Smoke2', 'SMK2', 'https://example.com/smoke2.json')
+      .launch('Smoke2', 'SMK2', 'https://example.com/smoke2.json', { constantProduct: {} }, null, null, null, new anchor.BN(0), null)
       .accounts({
         creator: admin.publicKey,
         globalConfig,
@@ -208,6 +230,10 @@ describe('devnet smoke: configure → launch → buy until completion → releas
         bondingCurve,
         curveTokenAccount,
         tokenMetadataAccount,
+        feeRecipient: admin.publicKey,
+        taxTreasury: currentConfig.taxTreasury,
+        creatorTokenAccount: getAssociatedTokenAddressSync(tokenMint.publicKey, admin.publicKey, false, TOKEN_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID),
+        creatorEarlyBuyPosition: earlyBuyPositionPda(programId, tokenMint.publicKey, admin.publicKey),
         tokenProgram: TOKEN_PROGRAM_ID,
         associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         metadataProgram: METADATA_PROGRAM_ID,
@@ -219,11 +245,12 @@ describe('devnet smoke: configure → launch → buy until completion → releas
 
     await ensureAirdrop(connection, buyer.publicKey, 1 * LAMPORTS_PER_SOL);
     await program.methods
-      .swap(currentConfig.curveLimit.div(new anchor.BN(10)), 0, new anchor.BN(0))
+      .swap(currentConfig.curveLimit.div(new anchor.BN(10)), 0, new anchor.BN(0), null, null, false)
       .accounts({
         user: buyer.publicKey,
         globalConfig,
         feeRecipient: currentConfig.feeRecipient,
+        taxTreasury: currentConfig.taxTreasury,
         bondingCurve,
         tokenMint: tokenMint.publicKey,
         curveTokenAccount,
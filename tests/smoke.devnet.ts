@@ -23,6 +23,9 @@ function globalConfigPda(programId: PublicKey): PublicKey {
 function bondingCurvePda(programId: PublicKey, mint: PublicKey): PublicKey {
   return PublicKey.findProgramAddressSync([Buffer.from('bonding-curve'), mint.toBuffer()], programId)[0];
 }
+function earlyBuyPositionPda(programId: PublicKey, mint: PublicKey, user: PublicKey): PublicKey {
+  return PublicKey.findProgramAddressSync([Buffer.from('early-buy-position'), mint.toBuffer(), user.toBuffer()], programId)[0];
+}
 function metadataPda(mint: PublicKey): PublicKey {
   return PublicKey.findProgramAddressSync([
     Buffer.from('metadata'),
@@ -72,12 +75,26 @@ describe('devnet smoke: configure → launch → buy until completion → releas
       feeRecipient: (provider.wallet as any).publicKey,
       curveLimit: curveLimit,
       initialVirtualTokenReserves: new BN(500_000_000_000),
-      initialVirtualSolReserves: new BN(0),
-      initialRealTokenReserves: new BN(0),
+      initialVirtualSolReserves: new BN(30_000_000),
+      initialRealTokenReserves: new BN(400_000_000_000),
       totalTokenSupply: totalTokenSupply,
-      buyFeePercent: 0,
-      sellFeePercent: 0,
-      migrationFeePercent: 0,
+      buyFeeBps: 0,
+      sellFeeBps: 0,
+      migrationFeeBps: 0,
+      creatorFeeBps: 0,
+      referralFeeBps: 0,
+      launchFeeLamports: new BN(0),
+      allowedCurveKinds: 1, // constant product only
+      linearGrowthBps: 0,
+      exponentialGrowthBps: 0,
+      maxNameLen: 32,
+      maxSymbolLen: 10,
+      maxUriLen: 200,
+      earlyWindowSlots: new BN(0),
+      earlyWalletCap: new BN(0),
+      launchTaxBps: 0,
+      launchTaxDecaySlots: new BN(0),
+      taxTreasury: (provider.wallet as any).publicKey,
     };
 
     const globalConfig = globalConfigPda(programId);
@@ -94,7 +111,7 @@ describe('devnet smoke: configure → launch → buy until completion → releas
     // Try to reconfigure with a random non-admin signer
     const rando = Keypair.generate();
     await ensureAirdrop(connection, rando.publicKey, 1 * LAMPORTS_PER_SOL);
-    const cfg2 = { ...cfg, buyFeePercent: 1 };
+    const cfg2 = { ...cfg, buyFeeBps: 100 };
     let threw = false;
     try {
       await (program as any).methods
@@ -122,7 +139,7 @@ describe('devnet smoke: configure → launch → buy until completion → releas
     const uri = 'https://example.com/smoke.json';
 
     await (program as any).methods
-      .launch(name, symbol, uri)
+      .launch(name, symbol, uri, { constantProduct: {} }, null, null, null, new BN(0), null)
       .accounts({
         creator: (provider.wallet as any).publicKey,
         globalConfig,
@@ -130,6 +147,10 @@ describe('devnet smoke: configure → launch → buy until completion → releas
         bondingCurve,
         curveTokenAccount,
         tokenMetadataAccount,
+        feeRecipient: (provider.wallet as any).publicKey,
+        taxTreasury: (provider.wallet as any).publicKey,
+        creatorTokenAccount: getAssociatedTokenAddressSync(tokenMint.publicKey, (provider.wallet as any).publicKey, false, TOKEN_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID),
+        creatorEarlyBuyPosition: earlyBuyPositionPda(programId, tokenMint.publicKey, (provider.wallet as any).publicKey),
         tokenProgram: TOKEN_PROGRAM_ID,
         associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         metadataProgram: METADATA_PROGRAM_ID,
@@ -147,11 +168,12 @@ describe('devnet smoke: configure → launch → buy until completion → releas
 
     // Perform a buy that should push reserves >= curveLimit
     await (program as any).methods
-      .swap(new BN(curveLimit.toNumber()), 0, new BN(0))
+      .swap(new BN(curveLimit.toNumber()), 0, new BN(0), null, null, false)
       .accounts({
         user: buyer.publicKey,
         globalConfig,
         feeRecipient: (provider.wallet as any).publicKey,
+        taxTreasury: (provider.wallet as any).publicKey,
         bondingCurve,
         tokenMint: tokenMint.publicKey,
         curveTokenAccount,
@@ -224,7 +246,7 @@ describe('devnet smoke: configure → launch → buy until completion → releas
     const tokenMetadataAccount = metadataPda(tokenMint.publicKey);
 
     await (program as any).methods
-      .launch('Smoke2', 'SMK2', 'https://example.com/smoke2.json')
+      .launch('Smoke2', 'SMK2', 'https://example.com/smoke2.json', { constantProduct: {} }, null, null, null, new BN(0), null)
       .accounts({
         creator: (provider.wallet as any).publicKey,
         globalConfig,
@@ -232,6 +254,10 @@ describe('devnet smoke: configure → launch → buy until completion → releas
         bondingCurve,
         curveTokenAccount,
         tokenMetadataAccount,
+        feeRecipient: (provider.wallet as any).publicKey,
+        taxTreasury: (provider.wallet as any).publicKey,
+        creatorTokenAccount: getAssociatedTokenAddressSync(tokenMint.publicKey, (provider.wallet as any).publicKey, false, TOKEN_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID),
+        creatorEarlyBuyPosition: earlyBuyPositionPda(programId, tokenMint.publicKey, (provider.wallet as any).publicKey),
         tokenProgram: TOKEN_PROGRAM_ID,
         associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         metadataProgram: METADATA_PROGRAM_ID,
@@ -243,11 +269,12 @@ describe('devnet smoke: configure → launch → buy until completion → releas
 
     await ensureAirdrop(connection, buyer.publicKey, 1 * LAMPORTS_PER_SOL);
     await (program as any).methods
-      .swap(new BN(curveLimit.toNumber() / 10), 0, new BN(0))
+      .swap(new BN(curveLimit.toNumber() / 10), 0, new BN(0), null, null, false)
       .accounts({
         user: buyer.publicKey,
         globalConfig,
         feeRecipient: (provider.wallet as any).publicKey,
+        taxTreasury: (provider.wallet as any).publicKey,
         bondingCurve,
         tokenMint: tokenMint.publicKey,
         curveTokenAccount,